@@ -6,6 +6,11 @@ use tokio::net::{TcpListener, TcpStream};
 
 /// A wrapper around [`TcpListener`] that implements [`Stream`].
 ///
+/// The stream is never terminated: an accept error is yielded as an `Err`
+/// item and the listener keeps accepting afterwards, so transient failures
+/// such as `ECONNABORTED` or `EMFILE` can be logged without tearing down the
+/// accept loop.
+///
 /// [`TcpListener`]: struct@tokio::net::TcpListener
 /// [`Stream`]: trait@crate::Stream
 #[derive(Debug)]
@@ -0,0 +1,36 @@
+#![cfg(feature = "sync")]
+
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+use tokio_test::task::spawn;
+use tokio_test::{assert_pending, assert_ready};
+
+#[tokio::test]
+async fn receiver_stream_yields_items_then_ends() {
+    let (tx, rx) = mpsc::channel(8);
+
+    for i in 0..3 {
+        tx.send(i).await.unwrap();
+    }
+    drop(tx);
+
+    // The stream yields everything buffered and terminates once the last
+    // sender is gone, so it composes with collect-style combinators.
+    let items: Vec<i32> = ReceiverStream::new(rx).collect().await;
+    assert_eq!(items, vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn receiver_stream_wakes_on_send_after_pending() {
+    let (tx, rx) = mpsc::channel(8);
+    let mut stream = ReceiverStream::new(rx);
+
+    let mut next = spawn(stream.next());
+    assert_pending!(next.poll());
+
+    // A send after the stream returned pending must wake the waiting task.
+    tx.send("ping").await.unwrap();
+    assert!(next.is_woken());
+    assert_eq!(assert_ready!(next.poll()), Some("ping"));
+}
@@ -0,0 +1,111 @@
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::io::{IoSlice, Result};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::time::{sleep, Instant, Sleep};
+
+pin_project! {
+    /// An adapter that fails reads once the wrapped stream has been idle for
+    /// too long.
+    ///
+    /// Every successful read — including the final EOF — rearms a single
+    /// internal timer. If no data arrives within the configured duration, the
+    /// pending read fails with an error of kind
+    /// [`std::io::ErrorKind::TimedOut`]. This is the usual way for a server to
+    /// shed connections that have gone silent.
+    ///
+    /// The timer is rearmed, never stacked: activity pushes the one deadline
+    /// forward rather than scheduling additional timers. Writes are passed
+    /// through untouched and do not extend the deadline.
+    ///
+    /// Unlike [`MaxLifetimeStream`], a stream that stays active can live
+    /// forever.
+    ///
+    /// [`MaxLifetimeStream`]: crate::io::MaxLifetimeStream
+    pub struct IdleTimeoutStream<S> {
+        #[pin]
+        inner: S,
+        timeout: Duration,
+        deadline: Pin<Box<Sleep>>,
+    }
+}
+
+fn idle() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "stream was idle for too long",
+    )
+}
+
+impl<S> IdleTimeoutStream<S> {
+    /// Create a new `IdleTimeoutStream` wrapping `inner`.
+    ///
+    /// The idle clock starts immediately; each successful read resets it.
+    pub fn new(inner: S, timeout: Duration) -> IdleTimeoutStream<S> {
+        IdleTimeoutStream {
+            inner,
+            timeout,
+            deadline: Box::pin(sleep(timeout)),
+        }
+    }
+
+    /// Consumes the `IdleTimeoutStream`, returning the wrapped stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: AsyncRead> AsyncRead for IdleTimeoutStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<()>> {
+        let me = self.project();
+
+        // Data that is already available wins over an expired timer.
+        match me.inner.poll_read(cx, buf) {
+            Poll::Ready(Ok(())) => {
+                me.deadline.as_mut().reset(Instant::now() + *me.timeout);
+                Poll::Ready(Ok(()))
+            }
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+            Poll::Pending => {
+                if me.deadline.as_mut().poll(cx).is_ready() {
+                    return Poll::Ready(Err(idle()));
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<S: AsyncWrite> AsyncWrite for IdleTimeoutStream<S> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        self.project().inner.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.project().inner.poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<Result<usize>> {
+        self.project().inner.poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
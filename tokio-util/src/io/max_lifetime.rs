@@ -0,0 +1,106 @@
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::io::{IoSlice, Result};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::time::{sleep, Sleep};
+
+pin_project! {
+    /// An adapter that enforces a maximum lifetime on the wrapped stream.
+    ///
+    /// Once the configured duration has elapsed since the wrapper was created,
+    /// every read and write fails with an error of kind
+    /// [`std::io::ErrorKind::TimedOut`], regardless of how recently the stream
+    /// was active. This is useful for forcibly rotating long-lived connections,
+    /// for example to bound credential exposure.
+    ///
+    /// Unlike an idle timeout, activity on the stream does not extend the
+    /// deadline.
+    pub struct MaxLifetimeStream<S> {
+        #[pin]
+        inner: S,
+        deadline: Pin<Box<Sleep>>,
+    }
+}
+
+fn expired() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "stream exceeded its maximum lifetime",
+    )
+}
+
+impl<S> MaxLifetimeStream<S> {
+    /// Create a new `MaxLifetimeStream` wrapping `inner`.
+    ///
+    /// The deadline starts counting immediately; after `max_lifetime` has
+    /// elapsed all I/O on the wrapper fails.
+    pub fn new(inner: S, max_lifetime: Duration) -> MaxLifetimeStream<S> {
+        MaxLifetimeStream {
+            inner,
+            deadline: Box::pin(sleep(max_lifetime)),
+        }
+    }
+
+    /// Consumes the `MaxLifetimeStream`, returning the wrapped stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: AsyncRead> AsyncRead for MaxLifetimeStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<()>> {
+        let me = self.project();
+        if me.deadline.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(expired()));
+        }
+        me.inner.poll_read(cx, buf)
+    }
+}
+
+impl<S: AsyncWrite> AsyncWrite for MaxLifetimeStream<S> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        let me = self.project();
+        if me.deadline.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(expired()));
+        }
+        me.inner.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let me = self.project();
+        if me.deadline.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(expired()));
+        }
+        me.inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        // Shutting down is still allowed after the deadline so that the
+        // connection can be torn down cleanly.
+        self.project().inner.poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<Result<usize>> {
+        let me = self.project();
+        if me.deadline.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(expired()));
+        }
+        me.inner.poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
@@ -0,0 +1,107 @@
+use pin_project_lite::pin_project;
+use std::io::{IoSlice, Result};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+pin_project! {
+    /// An adapter that counts the bytes flowing through the wrapped stream.
+    ///
+    /// Every successful read and write delegates to the inner stream and adds
+    /// the transferred byte count to one of two shared [`AtomicU64`]
+    /// counters. Cloning the counters out via [`counters`] lets a metrics
+    /// task snapshot per-connection throughput without instrumenting any
+    /// call sites.
+    ///
+    /// The counters only track bytes that actually moved: short reads and
+    /// writes add what was transferred, and failed operations add nothing.
+    ///
+    /// [`counters`]: MeteredStream::counters
+    pub struct MeteredStream<S> {
+        #[pin]
+        inner: S,
+        read_bytes: Arc<AtomicU64>,
+        written_bytes: Arc<AtomicU64>,
+    }
+}
+
+impl<S> MeteredStream<S> {
+    /// Create a new `MeteredStream` wrapping `inner`, with both counters at
+    /// zero.
+    pub fn new(inner: S) -> MeteredStream<S> {
+        MeteredStream {
+            inner,
+            read_bytes: Arc::new(AtomicU64::new(0)),
+            written_bytes: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Returns handles to the `(read, written)` byte counters.
+    ///
+    /// The counters keep updating as the stream is used, so they can be
+    /// moved to a metrics task and sampled at any time.
+    pub fn counters(&self) -> (Arc<AtomicU64>, Arc<AtomicU64>) {
+        (self.read_bytes.clone(), self.written_bytes.clone())
+    }
+
+    /// Consumes the `MeteredStream`, returning the wrapped stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: AsyncRead> AsyncRead for MeteredStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<()>> {
+        let me = self.project();
+        let before = buf.filled().len();
+        let res = me.inner.poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = res {
+            let n = (buf.filled().len() - before) as u64;
+            me.read_bytes.fetch_add(n, Ordering::Relaxed);
+        }
+        res
+    }
+}
+
+impl<S: AsyncWrite> AsyncWrite for MeteredStream<S> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        let me = self.project();
+        let res = me.inner.poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = res {
+            me.written_bytes.fetch_add(n as u64, Ordering::Relaxed);
+        }
+        res
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.project().inner.poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<Result<usize>> {
+        let me = self.project();
+        let res = me.inner.poll_write_vectored(cx, bufs);
+        if let Poll::Ready(Ok(n)) = res {
+            me.written_bytes.fetch_add(n as u64, Ordering::Relaxed);
+        }
+        res
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
@@ -12,8 +12,10 @@
 
 mod copy_to_bytes;
 mod inspect;
+mod metered;
 mod read_buf;
 mod reader_stream;
+mod reset_as_eof;
 mod sink_writer;
 mod stream_reader;
 
@@ -22,10 +24,20 @@ cfg_io_util! {
     pub use self::sync_bridge::SyncIoBridge;
 }
 
+cfg_time! {
+    mod idle_timeout;
+    pub use self::idle_timeout::IdleTimeoutStream;
+
+    mod max_lifetime;
+    pub use self::max_lifetime::MaxLifetimeStream;
+}
+
 pub use self::copy_to_bytes::CopyToBytes;
 pub use self::inspect::{InspectReader, InspectWriter};
+pub use self::metered::MeteredStream;
 pub use self::read_buf::read_buf;
 pub use self::reader_stream::ReaderStream;
+pub use self::reset_as_eof::ResetAsEof;
 pub use self::sink_writer::SinkWriter;
 pub use self::stream_reader::StreamReader;
 pub use crate::util::{poll_read_buf, poll_write_buf};
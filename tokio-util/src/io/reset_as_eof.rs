@@ -0,0 +1,79 @@
+use pin_project_lite::pin_project;
+use std::io::{ErrorKind, IoSlice, Result};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+pin_project! {
+    /// An adapter that reports a connection reset on read as a clean EOF.
+    ///
+    /// Some peers tear connections down with an RST instead of a graceful
+    /// close, which surfaces as [`std::io::ErrorKind::ConnectionReset`] even
+    /// when all expected data has already been received. Wrapping the stream
+    /// in `ResetAsEof` maps that error — on reads only — to `Ok` with zero
+    /// bytes, the same signal a clean close produces.
+    ///
+    /// This deliberately loses the distinction between a reset and a real
+    /// EOF, so only opt in when a trailing reset is known to be benign, such
+    /// as an HTTP client that has already seen a complete response. Write
+    /// errors are passed through untouched.
+    pub struct ResetAsEof<S> {
+        #[pin]
+        inner: S,
+    }
+}
+
+impl<S> ResetAsEof<S> {
+    /// Create a new `ResetAsEof` wrapping `inner`.
+    pub fn new(inner: S) -> ResetAsEof<S> {
+        ResetAsEof { inner }
+    }
+
+    /// Consumes the `ResetAsEof`, returning the wrapped stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: AsyncRead> AsyncRead for ResetAsEof<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<()>> {
+        match self.project().inner.poll_read(cx, buf) {
+            // An untouched buffer is how `AsyncRead` signals EOF.
+            Poll::Ready(Err(err)) if err.kind() == ErrorKind::ConnectionReset => {
+                Poll::Ready(Ok(()))
+            }
+            other => other,
+        }
+    }
+}
+
+impl<S: AsyncWrite> AsyncWrite for ResetAsEof<S> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        self.project().inner.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.project().inner.poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<Result<usize>> {
+        self.project().inner.poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
@@ -95,3 +95,58 @@ where
         }
     }
 }
+
+/// A [`Listener`] wrapper that filters connections at accept time.
+///
+/// Each accepted connection's peer address is passed to a predicate before the
+/// connection is yielded. If the predicate returns `false` the socket is
+/// closed immediately and never surfaced to the caller, which makes cheap
+/// IP allow/deny lists possible at the edge without handing rejected peers a
+/// live connection.
+#[derive(Debug)]
+pub struct FilteredListener<L, F> {
+    listener: L,
+    filter: F,
+}
+
+impl<L, F> FilteredListener<L, F> {
+    /// Creates a new `FilteredListener`, accepting only connections whose
+    /// peer address satisfies `filter`.
+    pub fn new(listener: L, filter: F) -> FilteredListener<L, F>
+    where
+        L: Listener,
+        F: FnMut(&L::Addr) -> bool,
+    {
+        FilteredListener { listener, filter }
+    }
+
+    /// Consumes the `FilteredListener`, returning the wrapped listener.
+    pub fn into_inner(self) -> L {
+        self.listener
+    }
+}
+
+impl<L, F> Listener for FilteredListener<L, F>
+where
+    L: Listener,
+    F: FnMut(&L::Addr) -> bool,
+{
+    type Io = L::Io;
+    type Addr = L::Addr;
+
+    fn poll_accept(&mut self, cx: &mut Context<'_>) -> Poll<Result<(Self::Io, Self::Addr)>> {
+        loop {
+            let (io, addr) = futures_core::ready!(self.listener.poll_accept(cx))?;
+            if (self.filter)(&addr) {
+                return Poll::Ready(Ok((io, addr)));
+            }
+            // Rejected: dropping the socket closes it without registering any
+            // further interest.
+            drop(io);
+        }
+    }
+
+    fn local_addr(&self) -> Result<Self::Addr> {
+        self.listener.local_addr()
+    }
+}
@@ -13,3 +13,9 @@ pub use poll_semaphore::PollSemaphore;
 
 mod reusable_box;
 pub use reusable_box::ReusableBoxFuture;
+
+mod shared_gate;
+pub use shared_gate::{SharedGate, SharedGateReadGuard, SharedGateWriteGuard};
+
+mod shared_oneshot;
+pub use shared_oneshot::{shared_oneshot, SharedReceiver, SharedRecvError, SharedSender};
@@ -0,0 +1,86 @@
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// A fair gate admitting either up to a fixed number of concurrent readers or
+/// a single writer.
+///
+/// This is similar to an `RwLock` without data, but the number of concurrent
+/// readers is bounded by a configurable cap. Bounding read concurrency is
+/// useful when each reader consumes a real resource, such as a database
+/// connection.
+///
+/// Admission is strict FIFO: a writer that starts waiting before a reader is
+/// admitted before that reader, and vice versa. This prevents starvation in
+/// both directions.
+#[derive(Debug)]
+pub struct SharedGate {
+    semaphore: Semaphore,
+    max_readers: u32,
+}
+
+/// A guard admitting shared (read) access, returned by [`SharedGate::read`].
+///
+/// The read slot is released when this guard is dropped.
+#[derive(Debug)]
+pub struct SharedGateReadGuard<'a> {
+    _permit: SemaphorePermit<'a>,
+}
+
+/// A guard admitting exclusive (write) access, returned by
+/// [`SharedGate::write`].
+///
+/// Exclusive access is released when this guard is dropped.
+#[derive(Debug)]
+pub struct SharedGateWriteGuard<'a> {
+    _permit: SemaphorePermit<'a>,
+}
+
+impl SharedGate {
+    /// Creates a new `SharedGate` admitting up to `max_readers` concurrent
+    /// readers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_readers` is zero or exceeds
+    /// [`Semaphore::MAX_PERMITS`] when converted to a permit count.
+    pub fn new(max_readers: u32) -> SharedGate {
+        assert!(max_readers > 0, "max_readers must be greater than zero");
+        assert!(
+            max_readers as usize <= Semaphore::MAX_PERMITS,
+            "max_readers exceeds Semaphore::MAX_PERMITS"
+        );
+        SharedGate {
+            semaphore: Semaphore::new(max_readers as usize),
+            max_readers,
+        }
+    }
+
+    /// Returns the maximum number of concurrent readers.
+    pub fn max_readers(&self) -> u32 {
+        self.max_readers
+    }
+
+    /// Waits for a read slot, resolving once fewer than `max_readers` readers
+    /// and no writer hold the gate.
+    ///
+    /// Waiters are admitted in the order `read` and [`write`] were called.
+    ///
+    /// [`write`]: SharedGate::write
+    pub async fn read(&self) -> SharedGateReadGuard<'_> {
+        // The semaphore is never closed, so acquiring cannot fail.
+        let permit = self.semaphore.acquire().await.unwrap();
+        SharedGateReadGuard { _permit: permit }
+    }
+
+    /// Waits for exclusive access, resolving once no readers and no other
+    /// writer hold the gate.
+    ///
+    /// Waiters are admitted in the order [`read`] and `write` were called.
+    ///
+    /// [`read`]: SharedGate::read
+    pub async fn write(&self) -> SharedGateWriteGuard<'_> {
+        // A writer takes every permit, excluding all readers. The semaphore is
+        // never closed, so acquiring cannot fail.
+        let permit = self.semaphore.acquire_many(self.max_readers).await.unwrap();
+        SharedGateWriteGuard { _permit: permit }
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+
+use tokio::sync::watch;
+
+/// Creates a shared oneshot channel: one value, delivered to every receiver.
+///
+/// This fills the gap between [`tokio::sync::oneshot`], which has a single
+/// consumer, and [`tokio::sync::watch`], which is built for a stream of
+/// updates: the sender sends exactly once and every [`SharedReceiver`] —
+/// including clones made after the send — observes a copy of that value.
+///
+/// # Examples
+///
+/// ```
+/// use tokio_util::sync::shared_oneshot;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, rx) = shared_oneshot::<u32>();
+/// let rx2 = rx.clone();
+///
+/// tx.send(7);
+///
+/// assert_eq!(rx.recv().await, Ok(7));
+/// assert_eq!(rx2.recv().await, Ok(7));
+/// # }
+/// ```
+pub fn shared_oneshot<T: Clone>() -> (SharedSender<T>, SharedReceiver<T>) {
+    let (tx, rx) = watch::channel(None);
+    (SharedSender { tx }, SharedReceiver { rx })
+}
+
+/// Sends a single value to every [`SharedReceiver`].
+///
+/// Created by [`shared_oneshot`].
+#[derive(Debug)]
+pub struct SharedSender<T> {
+    tx: watch::Sender<Option<T>>,
+}
+
+/// Receives the value sent by a [`SharedSender`].
+///
+/// Receivers are cheaply cloneable; each clone independently resolves to a
+/// copy of the sent value, even if it is created after the send.
+///
+/// Created by [`shared_oneshot`].
+#[derive(Debug)]
+pub struct SharedReceiver<T> {
+    rx: watch::Receiver<Option<T>>,
+}
+
+/// Error returned by [`SharedReceiver::recv`] when the [`SharedSender`] was
+/// dropped without sending a value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SharedRecvError(());
+
+impl fmt::Display for SharedRecvError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "sender dropped without sending")
+    }
+}
+
+impl std::error::Error for SharedRecvError {}
+
+impl<T: Clone> SharedSender<T> {
+    /// Sends the value, waking every current and future receiver.
+    ///
+    /// Unlike [`tokio::sync::oneshot::Sender::send`], this cannot fail: the
+    /// value is retained for receivers subscribing later, so it is delivered
+    /// even if no receiver exists at the time of the call.
+    pub fn send(self, value: T) {
+        // An error here only means no receiver is currently alive; the value
+        // is still stored for receivers cloned from the sender's channel, of
+        // which there are none left. Either way there is nothing to report.
+        let _ = self.tx.send(Some(value));
+    }
+}
+
+impl<T: Clone> SharedReceiver<T> {
+    /// Waits for the value, resolving as soon as it has been sent.
+    ///
+    /// If the value was already sent — even before this receiver was cloned —
+    /// this resolves immediately. Returns an error if the [`SharedSender`]
+    /// was dropped without sending.
+    pub async fn recv(mut self) -> Result<T, SharedRecvError> {
+        match self.rx.wait_for(Option::is_some).await {
+            Ok(value) => Ok(value.clone().unwrap()),
+            Err(_) => Err(SharedRecvError(())),
+        }
+    }
+}
+
+impl<T> Clone for SharedReceiver<T> {
+    fn clone(&self) -> Self {
+        SharedReceiver {
+            rx: self.rx.clone(),
+        }
+    }
+}
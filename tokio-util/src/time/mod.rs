@@ -1,10 +1,12 @@
 //! Additional utilities for tracking time.
 //!
 //! This module provides additional utilities for executing code after a set period
-//! of time. Currently there is only one:
+//! of time:
 //!
 //! * `DelayQueue`: A queue where items are returned once the requested delay
 //!   has expired.
+//! * `RateLimiter`: A fixed-window limiter capping how many operations may
+//!   proceed per period.
 //!
 //! This type must be used from within the context of the `Runtime`.
 
@@ -14,6 +16,9 @@ mod wheel;
 
 pub mod delay_queue;
 
+mod rate_limiter;
+pub use rate_limiter::RateLimiter;
+
 #[doc(inline)]
 pub use delay_queue::DelayQueue;
 
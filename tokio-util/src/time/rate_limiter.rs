@@ -0,0 +1,108 @@
+//! A simple fixed-window rate limiter.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::{sleep_until, Instant};
+
+/// Limits callers to at most `rate` operations per window of `per`.
+///
+/// [`acquire`] returns immediately while the current window still has
+/// budget, and otherwise sleeps until the next window opens. The budget
+/// resets to `rate` at each window boundary; unused budget does not roll
+/// over, so an idle limiter never accumulates credit beyond `rate` no
+/// matter how many windows elapse.
+///
+/// The limiter is cheap to share behind an `Arc` and does not spawn any
+/// background task: refills are computed lazily from the clock when a
+/// caller arrives.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_util::time::RateLimiter;
+///
+/// # async fn dox() {
+/// let limiter = RateLimiter::new(10, Duration::from_secs(1));
+///
+/// loop {
+///     limiter.acquire().await;
+///     // at most ten of these per second
+///     # break;
+/// }
+/// # }
+/// ```
+///
+/// [`acquire`]: RateLimiter::acquire
+#[derive(Debug)]
+pub struct RateLimiter {
+    rate: u32,
+    per: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    window_start: Instant,
+    used: u32,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `rate` operations per `per`.
+    ///
+    /// The first window starts at the first call to [`acquire`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is zero or `per` is zero.
+    ///
+    /// [`acquire`]: RateLimiter::acquire
+    pub fn new(rate: u32, per: Duration) -> RateLimiter {
+        assert!(rate > 0, "rate must be nonzero");
+        assert!(per > Duration::ZERO, "per must be nonzero");
+        RateLimiter {
+            rate,
+            per,
+            state: Mutex::new(State {
+                window_start: Instant::now(),
+                used: 0,
+            }),
+        }
+    }
+
+    /// Waits until the current window has budget and consumes one unit.
+    ///
+    /// Completes immediately when fewer than `rate` acquisitions have
+    /// happened in the current window; otherwise sleeps until the window
+    /// rolls over. Waiters racing for the next window's budget are not
+    /// served in any particular order.
+    pub async fn acquire(&self) {
+        loop {
+            let deadline = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+
+                if now >= state.window_start + self.per {
+                    // Skip forward over however many whole windows went by,
+                    // keeping the boundaries aligned. The budget resets to
+                    // `rate` exactly once, so missed windows never
+                    // over-credit.
+                    let elapsed = now.duration_since(state.window_start);
+                    let into_window = elapsed.as_nanos() % self.per.as_nanos().max(1);
+                    state.window_start = now - Duration::from_nanos(into_window as u64);
+                    state.used = 0;
+                }
+
+                if state.used < self.rate {
+                    state.used += 1;
+                    return;
+                }
+
+                state.window_start + self.per
+            };
+
+            sleep_until(deadline).await;
+        }
+    }
+}
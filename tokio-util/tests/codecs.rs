@@ -62,6 +62,24 @@ fn lines_decoder() {
     assert_eq!(None, codec.decode_eof(buf).unwrap());
 }
 
+#[test]
+fn lines_decoder_partial_line_across_reads() {
+    let mut codec = LinesCodec::new();
+    let buf = &mut BytesMut::new();
+
+    // A line arriving in pieces stays buffered until its terminator shows
+    // up, then decodes as one line.
+    buf.put_slice(b"hel");
+    assert_eq!(None, codec.decode(buf).unwrap());
+    buf.put_slice(b"lo wor");
+    assert_eq!(None, codec.decode(buf).unwrap());
+    buf.put_slice(b"ld\r\nnext");
+    assert_eq!("hello world", codec.decode(buf).unwrap().unwrap());
+    assert_eq!(None, codec.decode(buf).unwrap());
+    buf.put_slice(b"\n");
+    assert_eq!("next", codec.decode(buf).unwrap().unwrap());
+}
+
 #[test]
 fn lines_decoder_max_length() {
     const MAX_LENGTH: usize = 6;
@@ -0,0 +1,68 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::ErrorKind;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::io::IdleTimeoutStream;
+
+#[tokio::test(start_paused = true)]
+async fn activity_rearms_the_deadline() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut client = IdleTimeoutStream::new(client, Duration::from_secs(10));
+
+    let mut buf = [0u8; 4];
+
+    // Each arrival resets the one idle timer, so a stream that keeps
+    // receiving outlives the timeout many times over.
+    for _ in 0..5 {
+        tokio::time::sleep(Duration::from_secs(8)).await;
+        server.write_all(b"beat").await.unwrap();
+        client.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"beat");
+    }
+
+    // 40 seconds in, the connection goes silent; the read fails once the
+    // rearmed deadline — not any earlier one — elapses.
+    let start = tokio::time::Instant::now();
+    let err = client.read_exact(&mut buf).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+    assert_eq!(start.elapsed(), Duration::from_secs(10));
+}
+
+#[tokio::test(start_paused = true)]
+async fn eof_counts_as_activity() {
+    let (client, server) = tokio::io::duplex(64);
+    let mut client = IdleTimeoutStream::new(client, Duration::from_secs(10));
+
+    tokio::time::sleep(Duration::from_secs(8)).await;
+    drop(server);
+
+    // The clean close arrives in time and reads as a normal EOF.
+    let mut buf = Vec::new();
+    client.read_to_end(&mut buf).await.unwrap();
+    assert!(buf.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn writes_do_not_extend_the_deadline() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut client = IdleTimeoutStream::new(client, Duration::from_secs(10));
+
+    let server = tokio::spawn(async move {
+        let mut buf = [0u8; 4];
+        while server.read_exact(&mut buf).await.is_ok() {}
+    });
+
+    // Outbound traffic alone does not count as activity.
+    for _ in 0..4 {
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        client.write_all(b"send").await.unwrap();
+    }
+
+    let mut buf = [0u8; 1];
+    let err = client.read_exact(&mut buf).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+
+    drop(client);
+    server.await.unwrap();
+}
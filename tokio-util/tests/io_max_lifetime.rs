@@ -0,0 +1,57 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::ErrorKind;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::io::MaxLifetimeStream;
+
+#[tokio::test(start_paused = true)]
+async fn busy_stream_expires_at_deadline() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let start = tokio::time::Instant::now();
+    let mut client = MaxLifetimeStream::new(client, Duration::from_secs(5));
+
+    // Keep the connection busy: constant traffic in both directions must not
+    // extend the lifetime.
+    let server = tokio::spawn(async move {
+        let mut buf = [0u8; 4];
+        while server.read_exact(&mut buf).await.is_ok() {
+            if server.write_all(&buf).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut buf = [0u8; 4];
+    loop {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        if let Err(e) = client.write_all(b"ping").await {
+            assert_eq!(e.kind(), ErrorKind::TimedOut);
+            break;
+        }
+        match client.read_exact(&mut buf).await {
+            Ok(_) => assert_eq!(&buf, b"ping"),
+            Err(e) => {
+                assert_eq!(e.kind(), ErrorKind::TimedOut);
+                break;
+            }
+        }
+    }
+
+    // The deadline fired even though the stream was never idle.
+    assert!(start.elapsed() >= Duration::from_secs(5));
+
+    drop(client);
+    server.await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn io_works_before_deadline() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut client = MaxLifetimeStream::new(client, Duration::from_secs(60));
+
+    client.write_all(b"hello").await.unwrap();
+    let mut buf = [0u8; 5];
+    server.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"hello");
+}
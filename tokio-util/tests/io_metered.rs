@@ -0,0 +1,41 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::atomic::Ordering;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::io::MeteredStream;
+
+#[tokio::test]
+async fn counters_track_both_directions() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut client = MeteredStream::new(client);
+    let (read_bytes, written_bytes) = client.counters();
+
+    client.write_all(b"request").await.unwrap();
+
+    let mut buf = [0u8; 7];
+    server.read_exact(&mut buf).await.unwrap();
+    server.write_all(b"okay").await.unwrap();
+
+    let mut buf = [0u8; 4];
+    client.read_exact(&mut buf).await.unwrap();
+
+    // The cloned-out handles see the totals without touching the stream.
+    assert_eq!(written_bytes.load(Ordering::Relaxed), 7);
+    assert_eq!(read_bytes.load(Ordering::Relaxed), 4);
+}
+
+#[tokio::test]
+async fn eof_and_flush_do_not_count() {
+    let (client, server) = tokio::io::duplex(64);
+    let mut client = MeteredStream::new(client);
+    let (read_bytes, written_bytes) = client.counters();
+
+    drop(server);
+
+    let mut buf = Vec::new();
+    client.read_to_end(&mut buf).await.unwrap();
+    client.flush().await.unwrap();
+
+    assert_eq!(read_bytes.load(Ordering::Relaxed), 0);
+    assert_eq!(written_bytes.load(Ordering::Relaxed), 0);
+}
@@ -0,0 +1,33 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::{Error, ErrorKind};
+
+use tokio::io::AsyncReadExt;
+use tokio_test::io::Builder;
+use tokio_util::io::ResetAsEof;
+
+#[tokio::test]
+async fn reset_after_data_reads_as_eof() {
+    let inner = Builder::new()
+        .read(b"complete response")
+        .read_error(Error::new(ErrorKind::ConnectionReset, "rst"))
+        .build();
+
+    // The abrupt reset after the payload is reported as a clean close.
+    let mut stream = ResetAsEof::new(inner);
+    let mut buf = Vec::new();
+    stream.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, b"complete response");
+}
+
+#[tokio::test]
+async fn other_read_errors_pass_through() {
+    let inner = Builder::new()
+        .read_error(Error::new(ErrorKind::BrokenPipe, "pipe"))
+        .build();
+
+    let mut stream = ResetAsEof::new(inner);
+    let mut buf = Vec::new();
+    let err = stream.read_to_end(&mut buf).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+}
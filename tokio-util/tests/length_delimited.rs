@@ -774,3 +774,24 @@ fn data(bytes: &[u8]) -> Poll<io::Result<Op>> {
 fn flush() -> Poll<io::Result<Op>> {
     Poll::Ready(Ok(Op::Flush))
 }
+
+#[tokio::test]
+async fn builder_round_trip_over_duplex() {
+    use futures::{SinkExt, StreamExt};
+
+    let (a, b) = tokio::io::duplex(256);
+
+    // Both ends use the same wire format: 3-byte big-endian length header.
+    let mut tx = LengthDelimitedCodec::builder()
+        .length_field_length(3)
+        .new_framed(a);
+    let mut rx = LengthDelimitedCodec::builder()
+        .length_field_length(3)
+        .new_framed(b);
+
+    for msg in [&b"abcde"[..], b"", b"fgh"] {
+        tx.send(Bytes::from_static(msg)).await.unwrap();
+        let frame = rx.next().await.unwrap().unwrap();
+        assert_eq!(frame, msg);
+    }
+}
@@ -260,3 +260,30 @@ fn start_send_panics_when_acquiring() {
     assert_pending!(reserve.poll());
     send.send_item(2).unwrap();
 }
+
+#[tokio::test]
+async fn forward_holds_item_while_channel_is_full() {
+    use futures::StreamExt;
+
+    let (send, mut recv) = channel(1);
+
+    let source = futures::stream::iter(vec![1, 2, 3]).map(Ok);
+    let mut forward = spawn(source.forward(PollSender::new(send)));
+
+    // The first item fills the channel; the second has been pulled from the
+    // stream but must be parked inside the sink, not dropped.
+    assert_pending!(forward.poll());
+
+    // Draining a slot hands the parked item over and pulls the next one.
+    assert_eq!(recv.recv().await, Some(1));
+    assert!(forward.is_woken());
+    assert_pending!(forward.poll());
+
+    assert_eq!(recv.recv().await, Some(2));
+    assert!(forward.is_woken());
+    assert_ready_ok!(forward.poll());
+
+    // The stream is exhausted and every item was delivered in order.
+    assert_eq!(recv.recv().await, Some(3));
+    assert_eq!(recv.recv().await, None);
+}
@@ -0,0 +1,50 @@
+#![warn(rust_2018_idioms)]
+#![cfg(not(target_os = "wasi"))] // Wasi doesn't support bind
+
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::net::{FilteredListener, Listener};
+
+#[tokio::test]
+async fn rejected_peer_is_closed_and_never_yielded() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let banned_port = Arc::new(AtomicU16::new(0));
+    let banned = banned_port.clone();
+    let mut listener =
+        FilteredListener::new(listener, move |peer: &std::net::SocketAddr| {
+            peer.port() != banned.load(Ordering::SeqCst)
+        });
+
+    // The first client is banned by source port, the second is allowed.
+    let mut rejected = TcpStream::connect(addr).await.unwrap();
+    banned_port.store(rejected.local_addr().unwrap().port(), Ordering::SeqCst);
+    let accepted = TcpStream::connect(addr).await.unwrap();
+
+    // The rejected connection is skipped: the first accept yields the second
+    // client.
+    let (_io, peer) = listener.accept().await.unwrap();
+    assert_eq!(peer, accepted.local_addr().unwrap());
+
+    // The rejected peer's socket was closed: reading observes EOF or reset.
+    let mut buf = [0u8; 1];
+    match rejected.read(&mut buf).await {
+        Ok(n) => assert_eq!(n, 0),
+        Err(e) => assert_eq!(e.kind(), std::io::ErrorKind::ConnectionReset),
+    }
+}
+
+#[tokio::test]
+async fn passing_peer_is_yielded() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let mut listener = FilteredListener::new(listener, |_: &std::net::SocketAddr| true);
+
+    let client = TcpStream::connect(addr).await.unwrap();
+    let (_io, peer) = listener.accept().await.unwrap();
+    assert_eq!(peer, client.local_addr().unwrap());
+}
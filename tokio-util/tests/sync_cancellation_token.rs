@@ -445,3 +445,47 @@ fn derives_send_sync() {
     assert_send::<WaitForCancellationFuture<'static>>();
     assert_sync::<WaitForCancellationFuture<'static>>();
 }
+
+#[test]
+fn single_cancel_wakes_every_waiter() {
+    let (waker, wake_counter) = new_count_waker();
+    let token = CancellationToken::new();
+    let child = token.child_token();
+
+    // A mix of parent and child waiters, all registered before the cancel.
+    let wait_1 = token.cancelled();
+    let wait_2 = token.cancelled();
+    let wait_3 = child.cancelled();
+    pin!(wait_1, wait_2, wait_3);
+
+    assert_eq!(
+        Poll::Pending,
+        wait_1.as_mut().poll(&mut Context::from_waker(&waker))
+    );
+    assert_eq!(
+        Poll::Pending,
+        wait_2.as_mut().poll(&mut Context::from_waker(&waker))
+    );
+    assert_eq!(
+        Poll::Pending,
+        wait_3.as_mut().poll(&mut Context::from_waker(&waker))
+    );
+    assert_eq!(wake_counter, 0);
+
+    // One call wakes all three, across the parent/child boundary.
+    token.cancel();
+    assert_eq!(wake_counter, 3);
+
+    assert_eq!(
+        Poll::Ready(()),
+        wait_1.as_mut().poll(&mut Context::from_waker(&waker))
+    );
+    assert_eq!(
+        Poll::Ready(()),
+        wait_2.as_mut().poll(&mut Context::from_waker(&waker))
+    );
+    assert_eq!(
+        Poll::Ready(()),
+        wait_3.as_mut().poll(&mut Context::from_waker(&waker))
+    );
+}
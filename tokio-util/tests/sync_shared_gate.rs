@@ -0,0 +1,72 @@
+#![warn(rust_2018_idioms)]
+
+use tokio_test::task::spawn;
+use tokio_test::{assert_pending, assert_ready};
+use tokio_util::sync::SharedGate;
+
+#[test]
+fn reader_cap_is_enforced() {
+    let gate = SharedGate::new(2);
+
+    let g1 = assert_ready!(spawn(gate.read()).poll());
+    let g2 = assert_ready!(spawn(gate.read()).poll());
+
+    // The cap is reached: a third reader must wait.
+    let mut r3 = spawn(gate.read());
+    assert_pending!(r3.poll());
+
+    drop(g1);
+    assert!(r3.is_woken());
+    let _g3 = assert_ready!(r3.poll());
+
+    drop(g2);
+}
+
+#[test]
+fn writer_excludes_readers() {
+    let gate = SharedGate::new(4);
+
+    let w = assert_ready!(spawn(gate.write()).poll());
+
+    let mut r = spawn(gate.read());
+    assert_pending!(r.poll());
+
+    drop(w);
+    assert!(r.is_woken());
+    let _g = assert_ready!(r.poll());
+}
+
+#[test]
+fn writer_is_admitted_fifo() {
+    let gate = SharedGate::new(2);
+
+    let g1 = assert_ready!(spawn(gate.read()).poll());
+    let g2 = assert_ready!(spawn(gate.read()).poll());
+
+    // The writer queues up first, then another reader.
+    let mut w = spawn(gate.write());
+    assert_pending!(w.poll());
+    let mut r3 = spawn(gate.read());
+    assert_pending!(r3.poll());
+
+    // Releasing one reader is not enough for the writer, and the later
+    // reader must not jump the queue.
+    drop(g1);
+    assert_pending!(w.poll());
+    assert_pending!(r3.poll());
+
+    drop(g2);
+    assert!(w.is_woken());
+    let wg = assert_ready!(w.poll());
+    assert_pending!(r3.poll());
+
+    drop(wg);
+    assert!(r3.is_woken());
+    let _g3 = assert_ready!(r3.poll());
+}
+
+#[test]
+#[should_panic = "max_readers must be greater than zero"]
+fn zero_readers_panics() {
+    let _ = SharedGate::new(0);
+}
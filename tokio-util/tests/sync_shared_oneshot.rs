@@ -0,0 +1,47 @@
+#![warn(rust_2018_idioms)]
+
+use tokio_test::task::spawn;
+use tokio_test::{assert_pending, assert_ready};
+use tokio_util::sync::shared_oneshot;
+
+#[test]
+fn value_is_delivered_to_every_waiter() {
+    let (tx, rx) = shared_oneshot::<&str>();
+
+    let mut r1 = spawn(rx.clone().recv());
+    let mut r2 = spawn(rx.recv());
+    assert_pending!(r1.poll());
+    assert_pending!(r2.poll());
+
+    tx.send("done");
+
+    assert!(r1.is_woken());
+    assert!(r2.is_woken());
+    assert_eq!(assert_ready!(r1.poll()), Ok("done"));
+    assert_eq!(assert_ready!(r2.poll()), Ok("done"));
+}
+
+#[test]
+fn late_subscriber_still_gets_the_value() {
+    let (tx, rx) = shared_oneshot::<u32>();
+
+    tx.send(5);
+
+    // A receiver cloned after the send resolves immediately.
+    let late = rx.clone();
+    assert_eq!(assert_ready!(spawn(late.recv()).poll()), Ok(5));
+    assert_eq!(assert_ready!(spawn(rx.recv()).poll()), Ok(5));
+}
+
+#[test]
+fn dropped_sender_is_an_error() {
+    let (tx, rx) = shared_oneshot::<u32>();
+
+    let mut r = spawn(rx.recv());
+    assert_pending!(r.poll());
+
+    drop(tx);
+
+    assert!(r.is_woken());
+    assert!(assert_ready!(r.poll()).is_err());
+}
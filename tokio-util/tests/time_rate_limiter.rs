@@ -0,0 +1,71 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_util::time::RateLimiter;
+
+#[tokio::test(start_paused = true)]
+async fn budget_blocks_until_the_window_rolls_over() {
+    let limiter = RateLimiter::new(3, Duration::from_secs(1));
+    let start = Instant::now();
+
+    // The whole budget is available up front...
+    for _ in 0..3 {
+        limiter.acquire().await;
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    // ...and the fourth acquisition waits for the next window.
+    limiter.acquire().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn idle_windows_do_not_accumulate_credit() {
+    let limiter = RateLimiter::new(2, Duration::from_secs(1));
+    let start = Instant::now();
+
+    limiter.acquire().await;
+
+    // Sit idle across several windows; the budget refills to the cap, not
+    // to one credit per missed window.
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    limiter.acquire().await;
+    limiter.acquire().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(5));
+
+    // The third acquisition in this window has to wait for the boundary.
+    limiter.acquire().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(6));
+}
+
+#[tokio::test(start_paused = true)]
+async fn shared_limiter_throttles_spawned_tasks() {
+    let limiter = Arc::new(RateLimiter::new(2, Duration::from_secs(1)));
+    let start = Instant::now();
+
+    let mut handles = Vec::new();
+    for _ in 0..6 {
+        let limiter = limiter.clone();
+        handles.push(tokio::spawn(async move {
+            limiter.acquire().await;
+            start.elapsed()
+        }));
+    }
+
+    let mut elapsed: Vec<Duration> = Vec::new();
+    for handle in handles {
+        elapsed.push(handle.await.unwrap());
+    }
+    elapsed.sort();
+
+    // Six operations at two per second span three windows.
+    assert_eq!(elapsed[0], Duration::ZERO);
+    assert_eq!(elapsed[1], Duration::ZERO);
+    assert_eq!(elapsed[2], Duration::from_secs(1));
+    assert_eq!(elapsed[3], Duration::from_secs(1));
+    assert_eq!(elapsed[4], Duration::from_secs(2));
+    assert_eq!(elapsed[5], Duration::from_secs(2));
+}
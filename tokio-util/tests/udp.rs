@@ -144,3 +144,120 @@ async fn framed_half() -> std::io::Result<()> {
 
     Ok(())
 }
+
+/// Like `ByteCodec`, but reports an exhausted buffer as "no frame" so a
+/// single `UdpFramed` can be polled for multiple datagrams.
+pub struct DatagramCodec;
+
+impl Decoder for DatagramCodec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Vec<u8>>, io::Error> {
+        if buf.is_empty() {
+            return Ok(None);
+        }
+        let len = buf.len();
+        Ok(Some(buf.split_to(len).to_vec()))
+    }
+}
+
+impl Encoder<&[u8]> for DatagramCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, data: &[u8], buf: &mut BytesMut) -> Result<(), io::Error> {
+        buf.reserve(data.len());
+        buf.put_slice(data);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn three_frame_round_trip() -> std::io::Result<()> {
+    let a_soc = UdpSocket::bind("127.0.0.1:0").await?;
+    let b_soc = UdpSocket::bind("127.0.0.1:0").await?;
+
+    let a_addr = a_soc.local_addr()?;
+    let b_addr = b_soc.local_addr()?;
+
+    let mut a = UdpFramed::new(a_soc, DatagramCodec);
+    let mut b = UdpFramed::new(b_soc, DatagramCodec);
+
+    // Each encoded frame is flushed as one complete datagram.
+    let frames: [&[u8]; 3] = [b"first", b"second", b"third"];
+    for msg in frames {
+        a.send((msg, b_addr)).await?;
+    }
+
+    // All three arrive intact, in order, each tagged with the sender.
+    for expected in frames {
+        let (data, addr) = b.next().map(|e| e.unwrap()).await?;
+        assert_eq!(expected, &*data);
+        assert_eq!(a_addr, addr);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn framed_halves_on_shared_socket() -> std::io::Result<()> {
+    let soc = Arc::new(UdpSocket::bind("127.0.0.1:0").await?);
+
+    // One framed half only reads, the other only writes, both over the
+    // same socket; an echo loop in another task exercises them together.
+    let mut read_half = UdpFramed::new(soc.clone(), DatagramCodec);
+    let mut write_half = UdpFramed::new(soc, DatagramCodec);
+
+    let peer = UdpSocket::bind("127.0.0.1:0").await?;
+    let peer_addr = peer.local_addr()?;
+
+    let echo = tokio::spawn(async move {
+        let mut buf = [0u8; 32];
+        let (n, from) = peer.recv_from(&mut buf).await.unwrap();
+        peer.send_to(&buf[..n], from).await.unwrap();
+    });
+
+    write_half.send((&b"ping"[..], peer_addr)).await?;
+
+    let (data, from) = read_half.next().map(|e| e.unwrap()).await?;
+    assert_eq!(b"ping", &*data);
+    assert_eq!(peer_addr, from);
+
+    echo.await.unwrap();
+    Ok(())
+}
+
+#[tokio::test]
+async fn frames_keep_their_source_addr() -> std::io::Result<()> {
+    let a_soc = UdpSocket::bind("127.0.0.1:0").await?;
+    let b_soc = UdpSocket::bind("127.0.0.1:0").await?;
+    let c_soc = UdpSocket::bind("127.0.0.1:0").await?;
+
+    let a_addr = a_soc.local_addr()?;
+    let b_addr = b_soc.local_addr()?;
+    let c_addr = c_soc.local_addr()?;
+
+    let mut a = UdpFramed::new(a_soc, DatagramCodec);
+    let mut b = UdpFramed::new(b_soc, DatagramCodec);
+    let mut c = UdpFramed::new(c_soc, DatagramCodec);
+
+    // Interleave datagrams from two senders so several are queued before
+    // the receiver polls; each frame must carry the address of the socket
+    // that produced it, not the last one read.
+    a.send((&b"from-a-1"[..], c_addr)).await?;
+    b.send((&b"from-b-1"[..], c_addr)).await?;
+    a.send((&b"from-a-2"[..], c_addr)).await?;
+    b.send((&b"from-b-2"[..], c_addr)).await?;
+
+    for _ in 0..4 {
+        let (data, addr) = c.next().map(|e| e.unwrap()).await?;
+        if data.starts_with(b"from-a") {
+            assert_eq!(a_addr, addr);
+        } else {
+            assert!(data.starts_with(b"from-b"));
+            assert_eq!(b_addr, addr);
+        }
+    }
+
+    Ok(())
+}
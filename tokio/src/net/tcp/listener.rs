@@ -136,6 +136,15 @@ impl TcpListener {
     /// completes first, then it is guaranteed that no new connections were
     /// accepted by this method.
     ///
+    /// # Errors
+    ///
+    /// An error does not invalidate the listener: each call to `accept`
+    /// attempts a fresh `accept(2)`. Transient errors such as
+    /// `ECONNABORTED` (the peer disconnected during the handshake) or
+    /// `EMFILE`/`ENFILE` (the process or system is out of file descriptors)
+    /// should usually be logged and the loop continued, rather than tearing
+    /// down the accept loop.
+    ///
     /// [`TcpStream`]: struct@crate::net::TcpStream
     ///
     /// # Examples
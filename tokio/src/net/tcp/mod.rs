@@ -14,3 +14,6 @@ pub use split_owned::{OwnedReadHalf, OwnedWriteHalf, ReuniteError};
 
 pub(crate) mod stream;
 pub(crate) use stream::TcpStream;
+
+#[cfg(target_os = "linux")]
+pub use stream::TcpInfo;
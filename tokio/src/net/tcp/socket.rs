@@ -247,6 +247,12 @@ impl TcpSocket {
     /// Behavior is platform specific. Refer to the target platform's
     /// documentation for more details.
     ///
+    /// On Linux, setting this before `bind` lets several listeners share one
+    /// address, with the kernel distributing incoming connections between
+    /// them — the usual building block for one accept loop per worker. The
+    /// method only exists on platforms that support the option, so there is
+    /// no way to silently bind without it.
+    ///
     /// # Examples
     ///
     /// ```no_run
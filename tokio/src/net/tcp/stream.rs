@@ -71,6 +71,24 @@ cfg_net! {
     }
 }
 
+/// A subset of the kernel's per-connection TCP state, as reported by
+/// [`TcpStream::tcp_info`].
+#[cfg(target_os = "linux")]
+#[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct TcpInfo {
+    /// Smoothed round-trip time estimate.
+    pub rtt: Duration,
+    /// Round-trip time variance.
+    pub rtt_var: Duration,
+    /// Sender congestion window, in multiples of the maximum segment size.
+    pub snd_cwnd: u32,
+    /// Total number of segments retransmitted over the lifetime of the
+    /// connection.
+    pub total_retrans: u32,
+}
+
 impl TcpStream {
     cfg_not_wasi! {
         /// Opens a TCP connection to a remote host.
@@ -111,6 +129,31 @@ impl TcpStream {
         ///
         /// [`write_all`]: fn@crate::io::AsyncWriteExt::write_all
         /// [`AsyncWriteExt`]: trait@crate::io::AsyncWriteExt
+        ///
+        /// # Connection timeout
+        ///
+        /// The OS-level connect timeout can be very long. To bound how long to
+        /// wait for the handshake, wrap the connect future in
+        /// [`tokio::time::timeout`]: if the deadline fires first, the pending
+        /// connect future is dropped, which closes the socket and aborts the
+        /// in-flight connection attempt.
+        ///
+        /// ```no_run
+        /// use std::time::Duration;
+        /// use tokio::net::TcpStream;
+        /// use tokio::time::timeout;
+        ///
+        /// # async fn dox() -> Result<(), Box<dyn std::error::Error>> {
+        /// let stream = timeout(
+        ///     Duration::from_secs(5),
+        ///     TcpStream::connect("127.0.0.1:8080"),
+        /// ).await??;
+        /// # let _ = stream;
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [`tokio::time::timeout`]: crate::time::timeout
         pub async fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<TcpStream> {
             let addrs = to_socket_addrs(addr).await?;
 
@@ -1202,6 +1245,58 @@ impl TcpStream {
         pub fn set_linger(&self, dur: Option<Duration>) -> io::Result<()> {
             socket2::SockRef::from(self).set_linger(dur)
         }
+
+        /// Queries the kernel's `TCP_INFO` state for this connection.
+        ///
+        /// This reports a subset of the kernel's per-connection TCP state,
+        /// such as the smoothed round-trip time estimate and the current
+        /// congestion window. It is useful for monitoring connection quality
+        /// without instrumenting application traffic.
+        ///
+        /// This is only available on Linux.
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// use tokio::net::TcpStream;
+        ///
+        /// # async fn dox() -> Result<(), Box<dyn std::error::Error>> {
+        /// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+        ///
+        /// let info = stream.tcp_info()?;
+        /// println!("rtt: {:?}, cwnd: {} segments", info.rtt, info.snd_cwnd);
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[cfg(target_os = "linux")]
+        #[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+        pub fn tcp_info(&self) -> io::Result<TcpInfo> {
+            use std::os::unix::io::AsRawFd;
+
+            let mut info: libc::tcp_info = unsafe { std::mem::zeroed() };
+            let mut len = std::mem::size_of::<libc::tcp_info>() as libc::socklen_t;
+
+            let ret = unsafe {
+                libc::getsockopt(
+                    self.as_raw_fd(),
+                    libc::IPPROTO_TCP,
+                    libc::TCP_INFO,
+                    &mut info as *mut libc::tcp_info as *mut libc::c_void,
+                    &mut len,
+                )
+            };
+
+            if ret != 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            Ok(TcpInfo {
+                rtt: Duration::from_micros(info.tcpi_rtt.into()),
+                rtt_var: Duration::from_micros(info.tcpi_rttvar.into()),
+                snd_cwnd: info.tcpi_snd_cwnd,
+                total_retrans: info.tcpi_total_retrans,
+            })
+        }
     }
 
     /// Gets the value of the `IP_TTL` option for this socket.
@@ -512,6 +512,36 @@ impl Command {
         self
     }
 
+    /// Copies an environment variable from the current process to the child.
+    ///
+    /// If the variable is not set in the current process, this does nothing.
+    /// Combined with [`env_clear`], this builds a minimal environment that
+    /// passes through only the named variables.
+    ///
+    /// [`env_clear`]: Command::env_clear
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// # async fn test() { // allow using await
+    /// use tokio::process::Command;
+    ///
+    /// let output = Command::new("date")
+    ///         .env_clear()
+    ///         .env_inherit("TZ")
+    ///         .output().await.unwrap();
+    /// # }
+    /// ```
+    pub fn env_inherit<K: AsRef<OsStr>>(&mut self, key: K) -> &mut Command {
+        let key = key.as_ref();
+        if let Some(value) = std::env::var_os(key) {
+            self.std.env(key, value);
+        }
+        self
+    }
+
     /// Sets the working directory for the child process.
     ///
     /// # Platform-specific behavior
@@ -669,6 +699,15 @@ impl Command {
     /// Sets the child process's user ID. This translates to a
     /// `setuid` call in the child process. Failure in the `setuid`
     /// call will cause the spawn to fail.
+    ///
+    /// The ID is changed in the child after `fork` but before `exec`, so
+    /// the new program never runs with the parent's privileges. Note that
+    /// closures registered with [`pre_exec`] run *after* the `setgid` and
+    /// `setuid` calls, immediately before `exec`: anything a closure does
+    /// that requires the parent's privileges must happen in the parent
+    /// instead.
+    ///
+    /// [`pre_exec`]: Command::pre_exec
     #[cfg(unix)]
     #[cfg_attr(docsrs, doc(cfg(unix)))]
     pub fn uid(&mut self, id: u32) -> &mut Command {
@@ -1041,6 +1080,11 @@ enum FusedChild {
 /// The `Command::kill_on_drop` method can be used to modify this behavior
 /// and kill the child process if the `Child` wrapper is dropped before it
 /// has exited.
+///
+/// On Unix, a child that is dropped while still running is moved onto a
+/// global orphan queue driven by the runtime's `SIGCHLD` handling. Once the
+/// process exits it is waited on in the background, so dropping a `Child`
+/// does not accumulate zombie processes in long-running servers.
 #[derive(Debug)]
 pub struct Child {
     child: FusedChild,
@@ -1123,6 +1167,58 @@ impl Child {
         }
     }
 
+    /// Sends a signal to the child process.
+    ///
+    /// Unlike [`start_kill`], which always delivers `SIGKILL`, this allows
+    /// graceful-shutdown signals such as `SIGTERM`, `SIGINT` or `SIGHUP` to be
+    /// delivered.
+    ///
+    /// The signal is only sent while the child has not yet been reaped, which
+    /// guards against delivering it to an unrelated process that reused the
+    /// pid. If the child has already been polled to completion, an error of
+    /// kind [`io::ErrorKind::InvalidInput`] is returned.
+    ///
+    /// Note that, as with [`start_kill`], a terminated child remains a zombie
+    /// until it is waited on; the caller should ensure `child.wait().await` or
+    /// `child.try_wait()` is invoked afterwards.
+    ///
+    /// [`start_kill`]: Child::start_kill
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::process::Command;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    ///
+    ///     child.signal(libc::SIGTERM).expect("failed to signal child");
+    ///     child.wait().await.expect("failed to wait on child");
+    /// }
+    /// ```
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn signal(&mut self, signal: i32) -> io::Result<()> {
+        match &self.child {
+            FusedChild::Child(child) => {
+                let pid = child.inner.id() as libc::pid_t;
+                // SAFETY: this is a plain syscall; the pid is valid for the
+                // lifetime of `self.child` since the process is not yet reaped.
+                let ret = unsafe { libc::kill(pid, signal) };
+                if ret == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            }
+            FusedChild::Done(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid argument: can't signal an exited process",
+            )),
+        }
+    }
+
     /// Forces the child to exit.
     ///
     /// This is equivalent to sending a SIGKILL on unix platforms.
@@ -524,6 +524,14 @@ impl RuntimeMetrics {
     /// returned value may increase or decrease as new tasks are scheduled and
     /// processed.
     ///
+    /// The injection queue is unbounded; a depth that keeps rising indicates
+    /// tasks are being scheduled faster than the workers can pick them up. The
+    /// runtime does not apply backpressure on spawn, so spawn-heavy workloads
+    /// that want a bound should enforce one themselves, for example by gating
+    /// spawns on a [`Semaphore`].
+    ///
+    /// [`Semaphore`]: crate::sync::Semaphore
+    ///
     /// # Examples
     ///
     /// ```
@@ -886,6 +894,42 @@ cfg_net! {
             })
         }
 
+        /// Returns the number of file descriptors currently registered with the
+        /// runtime's I/O driver.
+        ///
+        /// This is the number of registrations that have not yet been
+        /// deregistered, i.e. the difference between
+        /// [`io_driver_fd_registered_count`] and
+        /// [`io_driver_fd_deregistered_count`].
+        ///
+        /// [`io_driver_fd_registered_count`]: RuntimeMetrics::io_driver_fd_registered_count
+        /// [`io_driver_fd_deregistered_count`]: RuntimeMetrics::io_driver_fd_deregistered_count
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let n = metrics.io_driver_fd_current_count();
+        ///     println!("{} fds are currently registered with the runtime's I/O driver.", n);
+        /// }
+        /// ```
+        pub fn io_driver_fd_current_count(&self) -> u64 {
+            self.with_io_driver_metrics(|m| {
+                // The counters are loaded independently, so a concurrent
+                // register/deregister pair can make the deregistered load
+                // observe more events than the earlier registered load.
+                // Saturate so a transient race can only under-report.
+                m.fd_registered_count
+                    .load(Relaxed)
+                    .saturating_sub(m.fd_deregistered_count.load(Relaxed))
+            })
+        }
+
         /// Returns the number of ready events processed by the runtime's
         /// I/O driver.
         ///
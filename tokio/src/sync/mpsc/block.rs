@@ -254,6 +254,12 @@ impl<T> Block<T> {
         self.header.ready_slots.load(Acquire) & READY_MASK == READY_MASK
     }
 
+    /// Returns `true` when the `TX_CLOSED` flag has been set on this block,
+    /// i.e. the close marker has been pushed into one of its slots.
+    pub(crate) fn is_closed(&self) -> bool {
+        is_tx_closed(self.header.ready_slots.load(Acquire))
+    }
+
     /// Returns the `observed_tail_position` value, if set
     pub(crate) fn observed_tail_position(&self) -> Option<usize> {
         if 0 == RELEASED & self.header.ready_slots.load(Acquire) {
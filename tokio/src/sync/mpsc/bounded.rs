@@ -451,6 +451,53 @@ impl<T> Receiver<T> {
         self.chan.close();
     }
 
+    /// Returns the number of messages in the channel.
+    ///
+    /// This is useful for emitting backpressure gauges showing how full the
+    /// channel is without draining it. The returned value never exceeds the
+    /// buffer capacity of the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::sync::mpsc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (tx, mut rx) = mpsc::channel(10);
+    ///
+    ///     tx.send(0).await.unwrap();
+    ///     tx.send(1).await.unwrap();
+    ///     assert_eq!(rx.len(), 2);
+    ///
+    ///     rx.recv().await.unwrap();
+    ///     assert_eq!(rx.len(), 1);
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chan.len()
+    }
+
+    /// Returns `true` if the channel contains no messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::sync::mpsc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (tx, rx) = mpsc::channel(10);
+    ///
+    ///     assert!(rx.is_empty());
+    ///     tx.send(0).await.unwrap();
+    ///     assert!(!rx.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chan.is_empty()
+    }
+
     /// Polls to receive the next message on this channel.
     ///
     /// This method returns:
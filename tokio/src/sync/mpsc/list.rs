@@ -93,6 +93,16 @@ impl<T> Tx<T> {
         unsafe { block.as_ref().tx_close() }
     }
 
+    /// Returns `true` if the close marker has been pushed.
+    pub(crate) fn is_closed(&self) -> bool {
+        let tail = self.block_tail.load(Acquire);
+
+        unsafe {
+            let tail_block = &*tail;
+            tail_block.is_closed()
+        }
+    }
+
     fn find_block(&self, slot_index: usize) -> NonNull<Block<T>> {
         // The start index of the block that contains `index`.
         let start_index = block::start_index(slot_index);
@@ -230,6 +240,15 @@ impl<T> fmt::Debug for Tx<T> {
 }
 
 impl<T> Rx<T> {
+    /// Returns the number of values pushed and not yet popped.
+    ///
+    /// When the senders have been dropped, the last claimed slot holds the
+    /// close marker rather than a value, which must not be counted.
+    pub(crate) fn len(&self, tx: &Tx<T>) -> usize {
+        let tail_position = tx.tail_position.load(Acquire);
+        tail_position - self.index - (tx.is_closed() as usize)
+    }
+
     /// Pops the next value off the queue.
     pub(crate) fn pop(&mut self, tx: &Tx<T>) -> Option<block::Read<T>> {
         // Advance `head`, if needed
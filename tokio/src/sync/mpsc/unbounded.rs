@@ -330,6 +330,49 @@ impl<T> UnboundedReceiver<T> {
         self.chan.close();
     }
 
+    /// Returns the number of messages in the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::sync::mpsc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (tx, mut rx) = mpsc::unbounded_channel();
+    ///
+    ///     tx.send(0).unwrap();
+    ///     tx.send(1).unwrap();
+    ///     assert_eq!(rx.len(), 2);
+    ///
+    ///     rx.recv().await.unwrap();
+    ///     assert_eq!(rx.len(), 1);
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chan.len()
+    }
+
+    /// Returns `true` if the channel contains no messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::sync::mpsc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (tx, rx) = mpsc::unbounded_channel();
+    ///
+    ///     assert!(rx.is_empty());
+    ///     tx.send(0).unwrap();
+    ///     assert!(!rx.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chan.is_empty()
+    }
+
     /// Polls to receive the next message on this channel.
     ///
     /// This method returns:
@@ -481,6 +481,34 @@ impl Semaphore {
         self.ll_sem.release(n);
     }
 
+    /// Decrease the number of available permits in the semaphore by a maximum
+    /// of `n`, permanently removing them from the pool.
+    ///
+    /// This is the counterpart of [`add_permits`] for scaling a dynamic
+    /// concurrency limit back down. Only currently-available permits can be
+    /// forgotten: permits that are held by outstanding
+    /// [`SemaphorePermit`]s are untouched and will return to the pool when
+    /// dropped. Returns the number of permits that were actually removed.
+    ///
+    /// [`add_permits`]: Semaphore::add_permits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::sync::Semaphore;
+    ///
+    /// let sem = Semaphore::new(10);
+    /// assert_eq!(sem.forget_permits(3), 3);
+    /// assert_eq!(sem.available_permits(), 7);
+    ///
+    /// // Only the available permits can be removed.
+    /// assert_eq!(sem.forget_permits(100), 7);
+    /// assert_eq!(sem.available_permits(), 0);
+    /// ```
+    pub fn forget_permits(&self, n: usize) -> usize {
+        self.ll_sem.forget_permits(n)
+    }
+
     /// Acquires a permit from the semaphore.
     ///
     /// If the semaphore has been closed, this returns an [`AcquireError`].
@@ -1115,6 +1115,51 @@ impl<T> Sender<T> {
         value
     }
 
+    /// Sends a new value via the channel, notifying all receivers, but only if
+    /// the new value differs from the current one.
+    ///
+    /// If the value is unchanged, receivers are not notified: their cached
+    /// version is left as-is and no wakeups occur. This is convenient for
+    /// values implementing [`PartialEq`] in scenarios like config reloads,
+    /// where most updates are no-ops and spurious wakeups are costly. For
+    /// in-place mutation, or types without [`PartialEq`], use
+    /// [`send_if_modified`] instead.
+    ///
+    /// Returns `true` if the value was updated (and receivers notified).
+    ///
+    /// This method permits sending values even when there are no receivers.
+    ///
+    /// [`send_if_modified`]: Sender::send_if_modified
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::sync::watch;
+    ///
+    /// let (tx, rx) = watch::channel("config-v1");
+    ///
+    /// // The same value again does not mark the channel as changed.
+    /// assert!(!tx.send_if_neq("config-v1"));
+    /// assert!(!rx.has_changed().unwrap());
+    ///
+    /// // A different value does.
+    /// assert!(tx.send_if_neq("config-v2"));
+    /// assert!(rx.has_changed().unwrap());
+    /// ```
+    pub fn send_if_neq(&self, new: T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.send_if_modified(|value| {
+            if *value == new {
+                false
+            } else {
+                *value = new;
+                true
+            }
+        })
+    }
+
     /// Returns a reference to the most recently sent value
     ///
     /// Outstanding borrows hold a read lock on the inner value. This means that
@@ -28,6 +28,20 @@ async fn create_all() {
     assert!(new_dir_2.is_dir());
 }
 
+#[tokio::test]
+async fn create_all_is_idempotent() {
+    let base_dir = tempdir().unwrap();
+    let new_dir = base_dir.path().join("foo").join("bar");
+
+    // Creating the same tree twice resolves `Ok(())` both times, while
+    // the non-recursive variant errors on the existing directory.
+    assert_ok!(fs::create_dir_all(&new_dir).await);
+    assert_ok!(fs::create_dir_all(&new_dir).await);
+    assert!(new_dir.is_dir());
+
+    assert_err!(fs::create_dir(&new_dir).await);
+}
+
 #[tokio::test]
 async fn build_dir() {
     let base_dir = tempdir().unwrap();
@@ -107,6 +121,28 @@ async fn read_inherent() {
     );
 }
 
+#[tokio::test]
+async fn read_large_dir_incrementally() {
+    let base_dir = tempdir().unwrap();
+
+    let p = base_dir.path();
+    for i in 0..256 {
+        std::fs::write(p.join(format!("{i:03}.txt")), b"x").unwrap();
+    }
+
+    // Entries are pulled one at a time, so a large directory can be
+    // processed without materializing the whole listing first.
+    let mut entries = fs::read_dir(p).await.unwrap();
+    let mut seen = 0;
+
+    while let Some(e) = assert_ok!(entries.next_entry().await) {
+        assert!(e.metadata().await.unwrap().is_file());
+        seen += 1;
+    }
+
+    assert_eq!(seen, 256);
+}
+
 #[tokio::test]
 async fn read_dir_entry_info() {
     let temp_dir = tempdir().unwrap();
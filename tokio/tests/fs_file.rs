@@ -24,6 +24,29 @@ async fn basic_read() {
     assert_eq!(&buf[..n], HELLO);
 }
 
+#[tokio::test]
+async fn read_to_end_reads_whole_file() {
+    let mut tempfile = tempfile();
+    // Larger than a single blocking-pool read chunk so the loop has to
+    // grow the buffer across several reads.
+    let contents: Vec<u8> = (0..512 * 1024).map(|i| i as u8).collect();
+    tempfile.write_all(&contents).unwrap();
+
+    let mut file = File::open(tempfile.path()).await.unwrap();
+
+    let mut buf = Vec::new();
+    let n = file.read_to_end(&mut buf).await.unwrap();
+
+    assert_eq!(n, contents.len());
+    assert_eq!(buf, contents);
+
+    // The file is handed back usable: seek to the start and read again.
+    file.rewind().await.unwrap();
+    let mut head = [0; 4];
+    file.read_exact(&mut head).await.unwrap();
+    assert_eq!(&head, &contents[..4]);
+}
+
 #[tokio::test]
 async fn basic_write() {
     let tempfile = tempfile();
@@ -97,6 +120,76 @@ async fn rewind_seek_position() {
     assert_eq!(file.stream_position().await.unwrap(), 0);
 }
 
+#[tokio::test]
+async fn seek_before_start_is_an_error() {
+    let mut tempfile = tempfile();
+    tempfile.write_all(HELLO).unwrap();
+
+    let mut file = File::open(tempfile.path()).await.unwrap();
+
+    // Seeking past the beginning of the file must surface an error,
+    // not panic or wrap around.
+    let err = file
+        .seek(SeekFrom::End(-(HELLO.len() as i64) - 1))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    // The handle stays usable at a valid position afterwards.
+    let pos = file.seek(SeekFrom::End(-4)).await.unwrap();
+    assert_eq!(pos, (HELLO.len() - 4) as u64);
+
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, &HELLO[HELLO.len() - 4..]);
+}
+
+#[tokio::test]
+async fn set_len_truncates_and_extends() {
+    let tempfile = tempfile();
+
+    let mut file = File::create(tempfile.path()).await.unwrap();
+    file.write_all(HELLO).await.unwrap();
+    file.flush().await.unwrap();
+
+    // Extending zero-fills the new region per OS semantics.
+    file.set_len(HELLO.len() as u64 + 6).await.unwrap();
+    let contents = std::fs::read(tempfile.path()).unwrap();
+    assert_eq!(&contents[..HELLO.len()], HELLO);
+    assert_eq!(&contents[HELLO.len()..], &[0; 6]);
+
+    // Truncating discards the tail; the handle stays usable for writes.
+    file.set_len(5).await.unwrap();
+    file.seek(SeekFrom::End(0)).await.unwrap();
+    file.write_all(b"!").await.unwrap();
+    file.flush().await.unwrap();
+
+    let contents = std::fs::read(tempfile.path()).unwrap();
+    assert_eq!(contents, b"hello!");
+}
+
+#[tokio::test]
+async fn metadata_from_handle_and_path() {
+    let mut tempfile = tempfile();
+    tempfile.write_all(HELLO).unwrap();
+
+    let mut file = File::open(tempfile.path()).await.unwrap();
+
+    // Handle-based metadata works without giving up the open file.
+    let metadata = file.metadata().await.unwrap();
+    assert!(metadata.is_file());
+    assert_eq!(metadata.len(), HELLO.len() as u64);
+
+    // The path-based query reports the same thing.
+    let by_path = tokio::fs::metadata(tempfile.path()).await.unwrap();
+    assert_eq!(by_path.len(), metadata.len());
+
+    // The handle is still usable afterwards.
+    let mut buf = [0; 1024];
+    let n = file.read(&mut buf).await.unwrap();
+    assert_eq!(&buf[..n], HELLO);
+}
+
 #[tokio::test]
 async fn coop() {
     let mut tempfile = tempfile();
@@ -176,6 +269,27 @@ async fn read_file_from_std() {
     assert_eq!(&buf[..n], HELLO);
 }
 
+#[tokio::test]
+async fn sync_all_and_sync_data_keep_handle_usable() {
+    let tempfile = tempfile();
+
+    let mut file = File::create(tempfile.path()).await.unwrap();
+    file.write_all(HELLO).await.unwrap();
+
+    // Flush the write to the OS, then force it to disk. Any fsync-time
+    // error (e.g. ENOSPC) would surface here rather than being swallowed.
+    file.flush().await.unwrap();
+    file.sync_data().await.unwrap();
+    file.sync_all().await.unwrap();
+
+    // The handle stays open for further writes after syncing.
+    file.write_all(b"more").await.unwrap();
+    file.sync_all().await.unwrap();
+
+    let on_disk = std::fs::read(tempfile.path()).unwrap();
+    assert_eq!(on_disk, [HELLO, b"more"].concat());
+}
+
 fn tempfile() -> NamedTempFile {
     NamedTempFile::new().unwrap()
 }
@@ -22,6 +22,22 @@ async fn open_with_open_options_and_read() {
     assert_eq!(&buf[..n], HELLO);
 }
 
+#[tokio::test]
+async fn open_options_create_new_existing_file() {
+    let mut tempfile = NamedTempFile::new().unwrap();
+    tempfile.write_all(HELLO).unwrap();
+
+    // `create_new` must refuse to clobber a file that already exists.
+    let err = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(tempfile.path())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+}
+
 #[tokio::test]
 async fn open_options_write() {
     // TESTING HACK: use Debug output to check the stored data
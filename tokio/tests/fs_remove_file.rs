@@ -22,3 +22,13 @@ async fn remove_file() {
         Err(_) => println!("ignored try_exists error after remove_file"),
     };
 }
+
+#[tokio::test]
+async fn remove_missing_file_is_not_found() {
+    let temp_dir = tempdir().unwrap();
+
+    let err = fs::remove_file(temp_dir.path().join("never-created.txt"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
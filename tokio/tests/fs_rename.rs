@@ -26,3 +26,21 @@ async fn rename_file() {
         Err(_) => println!("ignored try_exists error after rename"),
     };
 }
+
+#[tokio::test]
+async fn rename_replaces_existing_destination() {
+    let temp_dir = tempdir().unwrap();
+
+    let config = temp_dir.path().join("config.json");
+    let staged = temp_dir.path().join("config.json.tmp");
+
+    fs::write(&config, b"old contents").await.unwrap();
+
+    // The write-to-temp-then-swap pattern: stage the new contents, then
+    // rename over the live file in one step.
+    fs::write(&staged, b"new contents").await.unwrap();
+    fs::rename(&staged, &config).await.unwrap();
+
+    assert_eq!(fs::read(&config).await.unwrap(), b"new contents");
+    assert!(!fs::try_exists(&staged).await.unwrap());
+}
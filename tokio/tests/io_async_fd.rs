@@ -834,3 +834,32 @@ async fn await_error_readiness_invalid_address() {
     let guard = fd.ready(Interest::ERROR).await.unwrap();
     assert_eq!(guard.ready(), Ready::ERROR);
 }
+
+#[tokio::test]
+async fn narrowing_interest_keeps_queued_read_notification() {
+    use tokio::io::Interest;
+
+    let (a, mut b) = socketpair();
+
+    let afd_a = AsyncFd::new(a).unwrap();
+
+    // Data arrives while we are registered for both directions...
+    b.write_all(b"pending").unwrap();
+    let mut guard = afd_a.readable().await.unwrap();
+    guard.clear_ready();
+    drop(guard);
+
+    // ...then the registration is narrowed to read-only, the repo's way of
+    // dropping write interest (and its spurious wakeups) for an idle-write
+    // socket. The already-signalled read readiness must not be lost.
+    let a = afd_a.into_inner();
+    let afd_a = AsyncFd::with_interest(a, Interest::READABLE).unwrap();
+
+    let mut guard = afd_a.readable().await.unwrap();
+    let mut buf = [0u8; 16];
+    let n = guard
+        .try_io(|inner| inner.get_ref().read(&mut buf))
+        .unwrap()
+        .unwrap();
+    assert_eq!(&buf[..n], b"pending");
+}
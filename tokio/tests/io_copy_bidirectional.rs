@@ -88,6 +88,36 @@ async fn test_transfer_after_close() {
     .await
 }
 
+#[tokio::test]
+async fn counts_each_direction_through_half_close() {
+    // Not using `symmetric` here: the per-direction byte counts are
+    // deliberately different, so argument order matters.
+    let (mut a, mut a1) = make_socketpair().await;
+    let (mut b, mut b1) = make_socketpair().await;
+
+    let handle = tokio::spawn(async move { copy_bidirectional(&mut a1, &mut b1).await });
+
+    // a -> b carries eight bytes, then closes; the proxy must forward the
+    // EOF to b while continuing to relay b -> a.
+    a.write_all(b"eight by").await.unwrap();
+    AsyncWriteExt::shutdown(&mut a).await.unwrap();
+
+    let mut forwarded = Vec::new();
+    b.read_to_end(&mut forwarded).await.unwrap();
+    assert_eq!(forwarded, b"eight by");
+
+    // The b -> a direction is still open after the half-close.
+    b.write_all(b"four").await.unwrap();
+    let mut tmp = [0; 4];
+    a.read_exact(&mut tmp).await.unwrap();
+    assert_eq!(&tmp[..], b"four");
+
+    drop(b);
+
+    // (a_to_b, b_to_a)
+    assert_eq!(handle.await.unwrap().unwrap(), (8, 4));
+}
+
 #[tokio::test]
 async fn blocking_one_side_does_not_block_other() {
     symmetric(|handle, mut a, mut b| async move {
@@ -46,6 +46,35 @@ fn drop_wakes() {
     assert_ready!(task.poll());
 }
 
+#[test]
+fn drop_with_notification_in_flight() {
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpStream;
+
+    let rt = rt();
+
+    // Dropping an I/O object right after the peer has made it ready races
+    // stream teardown against the in-flight readiness event. The driver
+    // must quietly discard events for deregistered sources rather than
+    // waking (or panicking on) a token it no longer knows.
+    rt.block_on(async {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        for _ in 0..50 {
+            let client = TcpStream::connect(addr).await.unwrap();
+            let (mut server, _) = listener.accept().await.unwrap();
+
+            server.write_all(b"ready!").await.unwrap();
+            drop(client);
+
+            // Give the event a chance to surface after the drop.
+            tokio::task::yield_now().await;
+            drop(server);
+        }
+    });
+}
+
 fn rt() -> runtime::Runtime {
     runtime::Builder::new_current_thread()
         .enable_all()
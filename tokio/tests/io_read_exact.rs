@@ -13,3 +13,14 @@ async fn read_exact() {
     assert_eq!(n, 8);
     assert_eq!(buf[..], b"hello wo"[..]);
 }
+
+#[tokio::test]
+async fn read_exact_eof() {
+    let mut buf = Box::new([0; 8]);
+    let mut rd: &[u8] = b"short";
+
+    // The source ends before the buffer is full: surface `UnexpectedEof`
+    // rather than returning a partial read.
+    let err = rd.read_exact(&mut buf[..]).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
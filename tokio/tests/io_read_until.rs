@@ -72,3 +72,34 @@ async fn read_until_fail() {
     assert_eq!(err.to_string(), "The world has no end");
     assert_eq!(chunk, b"FooHello \xffWor");
 }
+
+#[tokio::test]
+#[cfg(not(target_os = "wasi"))]
+async fn read_until_over_tcp_with_small_buffer() {
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::{TcpListener, TcpStream};
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let peer = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        stream.write_all(b"first record;second;").await.unwrap();
+    });
+
+    // A capacity far smaller than the record forces several fill/consume
+    // cycles per `read_until`, exercising the partial-data carry-over.
+    let stream = TcpStream::connect(addr).await.unwrap();
+    let mut read = BufReader::with_capacity(4, stream);
+
+    let mut record = Vec::new();
+    let n = assert_ok!(read.read_until(b';', &mut record).await);
+    assert_eq!(n, b"first record;".len());
+    assert_eq!(record, b"first record;");
+
+    record.clear();
+    assert_ok!(read.read_until(b';', &mut record).await);
+    assert_eq!(record, b"second;");
+
+    peer.await.unwrap();
+}
@@ -72,3 +72,37 @@ async fn bad_reader_fails() {
 
     BadReader::new().take(10).read_buf(&mut buf).await.unwrap();
 }
+
+#[tokio::test]
+#[cfg(not(target_os = "wasi"))] // Wasi doesn't support bind
+async fn into_inner_resumes_exactly_after_the_limit() {
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::{TcpListener, TcpStream};
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let peer = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        // A fixed-length body followed by the next message on the same
+        // transport.
+        stream.write_all(b"body bytesNEXT").await.unwrap();
+    });
+
+    let stream = TcpStream::connect(addr).await.unwrap();
+    let mut body = stream.take(10);
+
+    // The limit shows up as EOF, even though more data is buffered.
+    let mut read = Vec::new();
+    body.read_to_end(&mut read).await.unwrap();
+    assert_eq!(read, b"body bytes");
+
+    // Nothing past the limit was consumed: the recovered stream picks up
+    // exactly where the body ended.
+    let mut stream = body.into_inner();
+    let mut next = [0u8; 4];
+    stream.read_exact(&mut next).await.unwrap();
+    assert_eq!(&next, b"NEXT");
+
+    peer.await.unwrap();
+}
@@ -19,3 +19,37 @@ async fn test_panics_do_not_propagate_when_dropping_join_handle() {
     tokio::time::sleep(std::time::Duration::from_millis(3)).await;
     drop(join_handle);
 }
+
+#[cfg(panic = "unwind")]
+#[tokio::test]
+async fn test_panic_payload_is_delivered_to_join_handle() {
+    let join_handle = tokio::spawn(async move {
+        panic!("boom: {}", 7);
+    });
+
+    let err = join_handle.await.unwrap_err();
+    assert!(err.is_panic());
+    assert!(!err.is_cancelled());
+
+    // The payload the task panicked with comes back exactly once, and can
+    // be downcast to the usual panic message types.
+    let payload = err.into_panic();
+    let msg = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap();
+    assert_eq!(msg, "boom: 7");
+}
+
+#[cfg(panic = "unwind")]
+#[tokio::test]
+async fn test_cancelled_task_is_not_a_panic() {
+    let join_handle = tokio::spawn(std::future::pending::<()>());
+
+    join_handle.abort();
+
+    let err = join_handle.await.unwrap_err();
+    assert!(err.is_cancelled());
+    assert!(!err.is_panic());
+}
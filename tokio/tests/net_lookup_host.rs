@@ -36,3 +36,25 @@ async fn resolve_dns() -> io::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn connect_hostname_tries_each_addr() -> io::Result<()> {
+    // Hostname-based connect resolves first, then attempts every returned
+    // address in order.
+    let listener = net::TcpListener::bind("127.0.0.1:0").await?;
+    let port = listener.local_addr()?.port();
+
+    let stream = net::TcpStream::connect(("localhost", port)).await?;
+    let (peer, _) = listener.accept().await?;
+    assert_eq!(stream.local_addr()?, peer.peer_addr()?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn connect_hostname_reports_last_error() {
+    // Nothing listens on this port; once every resolved address has been
+    // tried, the final connection error is surfaced.
+    let err = net::TcpStream::connect(("localhost", 1)).await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+}
@@ -0,0 +1,27 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", unix))]
+
+use tokio::process::Command;
+
+#[test]
+fn debug_shows_program_and_args() {
+    let mut cmd = Command::new("echo");
+    cmd.arg("hello").arg("two words");
+
+    // The derived impl delegates to `std::process::Command`, which renders
+    // the full command line with arguments quoted as needed.
+    let rendered = format!("{:?}", cmd);
+    assert!(rendered.contains("echo"), "missing program: {}", rendered);
+    assert!(rendered.contains("hello"), "missing arg: {}", rendered);
+    assert!(rendered.contains("two words"), "missing arg: {}", rendered);
+}
+
+#[test]
+fn debug_shows_cwd_and_env_overrides() {
+    let mut cmd = Command::new("ls");
+    cmd.current_dir("/tmp").env("MY_FLAG", "on");
+
+    let rendered = format!("{:?}", cmd);
+    assert!(rendered.contains("MY_FLAG"), "missing env: {}", rendered);
+    assert!(rendered.contains("/tmp"), "missing cwd: {}", rendered);
+}
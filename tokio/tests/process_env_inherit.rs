@@ -0,0 +1,49 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(unix, feature = "full", not(miri)))]
+
+use tokio::process::Command;
+
+async fn child_env(cmd: &mut Command, var: &str) -> Option<String> {
+    let output = cmd
+        .arg("-c")
+        .arg(format!("echo \"${{{var}:-unset}}\"", var = var))
+        .output()
+        .await
+        .unwrap();
+    assert!(output.status.success());
+    match String::from_utf8(output.stdout).unwrap().trim() {
+        "unset" => None,
+        value => Some(value.to_string()),
+    }
+}
+
+#[tokio::test]
+async fn env_inherit_composes_with_env_clear() {
+    const VAR: &str = "TOKIO_TEST_ENV_INHERIT";
+    std::env::set_var(VAR, "propagated");
+
+    // After `env_clear` the variable is only visible to the child if
+    // explicitly inherited.
+    let mut cleared = Command::new("/bin/sh");
+    cleared.env_clear();
+    assert_eq!(child_env(&mut cleared, VAR).await, None);
+
+    let mut inherited = Command::new("/bin/sh");
+    inherited.env_clear().env_inherit(VAR);
+    assert_eq!(
+        child_env(&mut inherited, VAR).await,
+        Some("propagated".to_string())
+    );
+}
+
+#[tokio::test]
+async fn env_inherit_ignores_unset_variables() {
+    let mut cmd = Command::new("/bin/sh");
+    cmd.env_clear()
+        .env_inherit("TOKIO_TEST_VAR_THAT_DOES_NOT_EXIST");
+
+    assert_eq!(
+        child_env(&mut cmd, "TOKIO_TEST_VAR_THAT_DOES_NOT_EXIST").await,
+        None
+    );
+}
@@ -42,3 +42,42 @@ async fn kill_on_drop() {
 
     assert_eq!("child ran\n", msg);
 }
+
+#[tokio::test]
+#[cfg(target_os = "linux")]
+async fn kill_on_drop_reaps_the_child() {
+    let mut cmd = Command::new("sleep");
+    cmd.arg("30").kill_on_drop(true);
+
+    let child = cmd.spawn().unwrap();
+    let pid = child.id().unwrap();
+
+    // Dropping kills the child; the drop path must also enqueue a reap so the
+    // killed process does not linger as a zombie.
+    drop(child);
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+    loop {
+        let stat = std::fs::read_to_string(format!("/proc/{pid}/stat"));
+        let zombie = match stat {
+            Ok(stat) => matches!(
+                stat.rsplit(')').next().and_then(|rest| rest.trim().chars().next()),
+                Some('Z')
+            ),
+            // Gone entirely: reaped.
+            Err(_) => false,
+        };
+        if !zombie {
+            break;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "killed child {pid} was never reaped"
+        );
+
+        // SIGCHLD from other children drives the orphan queue.
+        let mut probe = Command::new("true").spawn().unwrap();
+        probe.wait().await.unwrap();
+        sleep(Duration::from_millis(10)).await;
+    }
+}
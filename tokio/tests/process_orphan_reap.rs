@@ -0,0 +1,40 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(target_os = "linux", feature = "full"))]
+
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+
+fn is_zombie(pid: u32) -> bool {
+    match std::fs::read_to_string(format!("/proc/{pid}/stat")) {
+        // The state field follows the parenthesized command name.
+        Ok(stat) => matches!(
+            stat.rsplit(')').next().and_then(|rest| rest.trim().chars().next()),
+            Some('Z')
+        ),
+        // The pid is gone entirely: it was reaped.
+        Err(_) => false,
+    }
+}
+
+#[tokio::test]
+async fn dropped_child_is_reaped_in_background() {
+    let child = Command::new("sleep").arg("0").spawn().unwrap();
+    let pid = child.id().unwrap();
+
+    // Deliberately orphan the process without waiting on it.
+    drop(child);
+
+    // Spawning and waiting on further children delivers SIGCHLD, which drives
+    // the orphan queue. The dropped child must eventually stop being a zombie.
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while is_zombie(pid) {
+        assert!(
+            Instant::now() < deadline,
+            "orphaned child {pid} was never reaped"
+        );
+
+        let mut probe = Command::new("true").spawn().unwrap();
+        probe.wait().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
@@ -0,0 +1,53 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(unix, feature = "full", not(target_os = "wasi")))]
+
+use std::io;
+use std::process::Stdio;
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+use tokio_test::assert_ok;
+
+#[tokio::test]
+async fn pre_exec_runs_in_child_before_exec() {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c")
+        .arg("echo hello")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Redirect fd 1 to fd 2 in the child, after the pipes have been set up
+    // but before exec: the output must land on the stderr pipe.
+    unsafe {
+        cmd.pre_exec(|| {
+            if libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+
+    let mut child = cmd.spawn().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+    let mut stderr = child.stderr.take().unwrap();
+
+    let mut out = String::new();
+    let mut err = String::new();
+    assert_ok!(stdout.read_to_string(&mut out).await);
+    assert_ok!(stderr.read_to_string(&mut err).await);
+
+    assert!(out.is_empty());
+    assert_eq!(err.trim(), "hello");
+
+    let status = assert_ok!(child.wait().await);
+    assert!(status.success());
+}
+
+#[tokio::test]
+async fn pre_exec_error_fails_spawn() {
+    let mut cmd = Command::new("true");
+    unsafe {
+        cmd.pre_exec(|| Err(io::Error::new(io::ErrorKind::Other, "nope")));
+    }
+
+    assert!(cmd.spawn().is_err() || cmd.status().await.is_err());
+}
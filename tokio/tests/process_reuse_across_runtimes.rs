@@ -0,0 +1,31 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi")))] // Wasi cannot run system commands
+
+use tokio::process::Command;
+use tokio::runtime;
+
+#[test]
+fn command_template_spawns_on_multiple_runtimes() {
+    // A `Command` is not tied to the runtime that existed when it was
+    // built; the child registers with whichever runtime drives the spawn.
+    // That makes a shared command template usable from per-worker runtimes.
+    let mut cmd;
+    if cfg!(windows) {
+        cmd = Command::new("cmd");
+        cmd.arg("/c");
+    } else {
+        cmd = Command::new("sh");
+        cmd.arg("-c");
+    }
+    cmd.arg("exit 0");
+
+    for _ in 0..2 {
+        let rt = runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let status = rt.block_on(async { cmd.status().await }).unwrap();
+        assert!(status.success());
+    }
+}
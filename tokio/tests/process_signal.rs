@@ -0,0 +1,35 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(unix, feature = "full", not(target_os = "wasi")))]
+
+use std::os::unix::process::ExitStatusExt;
+use tokio::process::Command;
+use tokio_test::assert_ok;
+
+#[tokio::test]
+async fn signal_terminates_child() {
+    let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+
+    assert_ok!(child.signal(libc::SIGTERM));
+
+    let status = assert_ok!(child.wait().await);
+    assert_eq!(status.signal(), Some(libc::SIGTERM));
+}
+
+#[tokio::test]
+async fn signal_after_reap_is_an_error() {
+    let mut child = Command::new("true").spawn().unwrap();
+    assert_ok!(child.wait().await);
+
+    let err = child.signal(libc::SIGTERM).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[tokio::test]
+async fn signal_zero_probes_liveness() {
+    let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+
+    // Signal 0 performs error checking only.
+    assert_ok!(child.signal(0));
+
+    assert_ok!(child.kill().await);
+}
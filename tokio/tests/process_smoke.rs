@@ -32,3 +32,26 @@ async fn simple() {
     assert_eq!(child.id(), None);
     drop(child.kill());
 }
+
+#[tokio::test]
+async fn status_chains_spawn_and_wait() {
+    let shell = |script: &str| {
+        let mut cmd;
+        if cfg!(windows) {
+            cmd = Command::new("cmd");
+            cmd.arg("/c");
+        } else {
+            cmd = Command::new("sh");
+            cmd.arg("-c");
+        }
+        cmd.arg(script);
+        cmd
+    };
+
+    // One future from command to exit status, no intermediate `Child`.
+    let status = assert_ok!(shell("exit 0").status().await);
+    assert!(status.success());
+
+    let status = assert_ok!(shell("exit 3").status().await);
+    assert_eq!(status.code(), Some(3));
+}
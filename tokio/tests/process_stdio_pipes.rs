@@ -0,0 +1,81 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi")))] // Wasi cannot run system commands
+
+use std::process::Stdio;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::Command;
+use tokio_test::assert_ok;
+
+fn shell(script: &str) -> Command {
+    let mut cmd;
+    if cfg!(windows) {
+        cmd = Command::new("cmd");
+        cmd.arg("/c");
+    } else {
+        cmd = Command::new("sh");
+        cmd.arg("-c");
+    }
+    cmd.arg(script);
+    cmd
+}
+
+#[tokio::test]
+async fn captured_stdout_reads_to_eof() {
+    let mut child = shell("echo hello")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdout = child.stdout.take().unwrap();
+
+    // The reader surfaces EOF (`Ok(0)`) once the child closes its end rather
+    // than hanging.
+    let mut out = String::new();
+    assert_ok!(stdout.read_to_string(&mut out).await);
+    assert_eq!(out.trim(), "hello");
+
+    let mut buf = [0u8; 8];
+    assert_eq!(assert_ok!(stdout.read(&mut buf).await), 0);
+
+    let status = assert_ok!(child.wait().await);
+    assert!(status.success());
+}
+
+#[tokio::test]
+async fn piped_stdin_round_trips_to_stdout() {
+    let mut child = shell(if cfg!(windows) { "findstr x*" } else { "cat" })
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+
+    assert_ok!(stdin.write_all(b"ping\n").await);
+    drop(stdin);
+
+    let mut out = String::new();
+    assert_ok!(stdout.read_to_string(&mut out).await);
+    assert_eq!(out.trim(), "ping");
+
+    let status = assert_ok!(child.wait().await);
+    assert!(status.success());
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn output_drains_both_pipes_without_deadlock() {
+    // Write well past the pipe buffer size on both stdout and stderr; if the
+    // pipes were drained sequentially the child would block on one of them.
+    let script = "i=0; while [ $i -lt 20000 ]; do \
+                  echo oooooooooooooooooooooooooooooooo; \
+                  echo eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee >&2; \
+                  i=$((i+1)); done";
+
+    let output = assert_ok!(shell(script).output().await);
+
+    assert!(output.status.success());
+    assert_eq!(output.stdout.len(), 33 * 20000);
+    assert_eq!(output.stderr.len(), 33 * 20000);
+}
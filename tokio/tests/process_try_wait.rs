@@ -0,0 +1,57 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi")))] // Wasi cannot run system commands
+
+use tokio::process::Command;
+use tokio_test::assert_ok;
+
+fn sleep_cmd(secs: &str) -> Command {
+    let mut cmd;
+    if cfg!(windows) {
+        cmd = Command::new("cmd");
+        cmd.arg("/c").arg("timeout").arg("/t");
+    } else {
+        cmd = Command::new("sleep");
+    }
+    cmd.arg(secs);
+    cmd
+}
+
+#[tokio::test]
+async fn try_wait_running_child_returns_none() {
+    let mut child = sleep_cmd("30").spawn().unwrap();
+
+    // Still running: the handle is not consumed and the child stays ours.
+    assert!(assert_ok!(child.try_wait()).is_none());
+    assert!(child.id().is_some());
+
+    assert_ok!(child.kill().await);
+}
+
+#[tokio::test]
+async fn try_wait_after_exit_returns_cached_status() {
+    let mut child = sleep_cmd("0").spawn().unwrap();
+
+    let status = assert_ok!(child.wait().await);
+
+    // Once reaped, `try_wait` keeps returning the cached status instead of
+    // erroring.
+    for _ in 0..3 {
+        assert_eq!(assert_ok!(child.try_wait()), Some(status));
+    }
+}
+
+#[tokio::test]
+async fn try_wait_reaps_without_wait() {
+    let mut child = sleep_cmd("0").spawn().unwrap();
+
+    // Poll until the exit status shows up, without ever awaiting `wait`.
+    let status = loop {
+        if let Some(status) = assert_ok!(child.try_wait()) {
+            break status;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    };
+
+    assert!(status.success());
+    assert!(child.id().is_none());
+}
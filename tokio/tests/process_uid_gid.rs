@@ -0,0 +1,37 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(unix, feature = "full", not(miri)))]
+
+use tokio::process::Command;
+
+#[tokio::test]
+async fn uid_and_gid_take_effect_before_exec() {
+    let uid = unsafe { libc::geteuid() };
+    let gid = unsafe { libc::getegid() };
+
+    // Setting the current IDs always succeeds, whether or not we are
+    // privileged, and the spawned program must observe them.
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg("echo \"$(id -u) $(id -g)\"")
+        .uid(uid)
+        .gid(gid)
+        .output()
+        .await
+        .unwrap();
+
+    assert!(output.status.success());
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(out.trim(), format!("{} {}", uid, gid));
+}
+
+#[tokio::test]
+async fn failing_setuid_fails_the_spawn() {
+    // Only meaningful without privileges: switching to another user is
+    // then forbidden and must surface as a spawn error.
+    if unsafe { libc::geteuid() } == 0 {
+        return;
+    }
+
+    let res = Command::new("true").uid(0).spawn();
+    assert!(res.is_err());
+}
@@ -0,0 +1,42 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi")))] // Wasi cannot run system commands
+
+use std::time::Duration;
+use tokio::process::Command;
+use tokio_test::assert_ok;
+
+fn sleep_cmd(secs: &str) -> Command {
+    let mut cmd;
+    if cfg!(windows) {
+        cmd = Command::new("cmd");
+        cmd.arg("/c").arg("timeout").arg("/t");
+    } else {
+        cmd = Command::new("sleep");
+    }
+    cmd.arg(secs);
+    cmd
+}
+
+// `Child::wait` is cancel safe, so a grace period is expressed by racing it
+// against the timer: if the timer wins, the child is untouched and can still
+// be killed or waited on.
+#[tokio::test]
+async fn wait_timeout_leaves_child_alive() {
+    let mut child = sleep_cmd("30").spawn().unwrap();
+
+    let res = tokio::time::timeout(Duration::from_millis(100), child.wait()).await;
+    assert!(res.is_err(), "child should not have exited yet");
+
+    // The timer won: the child is still running and owned by us.
+    assert!(child.id().is_some());
+    assert_ok!(child.kill().await);
+}
+
+#[tokio::test]
+async fn wait_timeout_returns_status_on_exit() {
+    let mut child = sleep_cmd("0").spawn().unwrap();
+
+    let res = tokio::time::timeout(Duration::from_secs(30), child.wait()).await;
+    let status = assert_ok!(res.expect("child should exit within the timeout"));
+    assert!(status.success());
+}
@@ -1067,6 +1067,28 @@ rt_test! {
         assert!(now.elapsed().as_secs() < 1);
     }
 
+    #[cfg(not(target_os="wasi"))] // Wasi does not support threads
+    #[test]
+    fn shutdown_timeout_drops_pending_tasks() {
+        let runtime = rt();
+        let flag = Arc::new(());
+        let in_task = flag.clone();
+
+        runtime.block_on(async move {
+            task::spawn(async move {
+                let _held = in_task;
+                futures::future::pending::<()>().await;
+            });
+            // Let the task get polled once so it is live when we shut down.
+            task::yield_now().await;
+        });
+
+        // The timeout elapses with the task still pending; the runtime must
+        // drop it (running destructors) rather than leak it.
+        Arc::try_unwrap(runtime).unwrap().shutdown_timeout(Duration::from_millis(100));
+        assert_eq!(Arc::strong_count(&flag), 1);
+    }
+
     #[test]
     fn shutdown_wakeup_time() {
         let runtime = rt();
@@ -87,6 +87,27 @@ mod unstable {
     }
 }
 
+#[test]
+fn spawned_join_handle_outlives_spawning_task() {
+    let rt = rt();
+
+    rt.block_on(async {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        // A short-lived task hands back the join handle of a background
+        // task it spawned via the handle; observing the result must not
+        // depend on the spawner still being alive.
+        let spawner = tokio::spawn(async move {
+            let background = tokio::runtime::Handle::current().spawn(async { 7 });
+            tx.send(background).unwrap();
+        });
+        spawner.await.unwrap();
+
+        let background = rx.await.unwrap();
+        assert_eq!(background.await.unwrap(), 7);
+    });
+}
+
 fn rt() -> Runtime {
     tokio::runtime::Builder::new_current_thread()
         .build()
@@ -702,6 +702,26 @@ fn io_driver_fd_count() {
     assert_eq!(metrics.io_driver_fd_registered_count(), 1);
 }
 
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[test]
+fn io_driver_fd_current_count() {
+    let rt = current_thread();
+    let metrics = rt.metrics();
+
+    assert_eq!(metrics.io_driver_fd_current_count(), 0);
+
+    let listener = rt.block_on(async { tokio::net::TcpListener::bind("127.0.0.1:0").await });
+    let listener = listener.unwrap();
+
+    assert_eq!(metrics.io_driver_fd_current_count(), 1);
+
+    drop(listener);
+
+    assert_eq!(metrics.io_driver_fd_current_count(), 0);
+    assert_eq!(metrics.io_driver_fd_registered_count(), 1);
+    assert_eq!(metrics.io_driver_fd_deregistered_count(), 1);
+}
+
 #[cfg(any(target_os = "linux", target_os = "macos"))]
 #[test]
 fn io_driver_ready_count() {
@@ -71,6 +71,24 @@ fn send_try_recv_bounded() {
     assert_empty!(rx);
 }
 
+#[test]
+fn two_senders_fan_out_to_all_receivers() {
+    let (tx1, mut rx1) = broadcast::channel(16);
+    let tx2 = tx1.clone();
+    let mut rx2 = tx1.subscribe();
+
+    // Every receiver sees every message, regardless of which producer
+    // sent it, in send order.
+    assert_ok!(tx1.send("from tx1"));
+    assert_ok!(tx2.send("from tx2"));
+
+    for rx in [&mut rx1, &mut rx2] {
+        assert_eq!(assert_recv!(rx), "from tx1");
+        assert_eq!(assert_recv!(rx), "from tx2");
+        assert_empty!(rx);
+    }
+}
+
 #[test]
 fn send_two_recv() {
     let (tx, mut rx1) = broadcast::channel(16);
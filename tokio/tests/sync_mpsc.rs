@@ -434,6 +434,155 @@ async fn send_recv_buffer_limited() {
     assert!(rx.recv().await.is_some());
 }
 
+#[maybe_tokio_test]
+async fn recv_many_returns_before_limit() {
+    let (tx, mut rx) = mpsc::channel(10);
+    let mut buffer: Vec<i32> = vec![];
+
+    {
+        let mut fut = tokio_test::task::spawn(rx.recv_many(&mut buffer, 8));
+        assert_pending!(fut.poll());
+
+        // The first message resolves the future: it does not wait until
+        // `limit` messages have accumulated.
+        assert_ok!(tx.send(1).await);
+        assert!(fut.is_woken());
+        assert_eq!(assert_ready!(fut.poll()), 1);
+    }
+
+    assert_eq!(buffer, vec![1]);
+
+    // Already-buffered messages are drained greedily up to the limit.
+    for i in 2..=5 {
+        assert_ok!(tx.send(i).await);
+    }
+    assert_eq!(rx.recv_many(&mut buffer, 3).await, 3);
+    assert_eq!(buffer, vec![1, 2, 3, 4]);
+}
+
+#[maybe_tokio_test]
+async fn len_and_is_empty() {
+    let (tx, mut rx) = mpsc::channel(16);
+
+    assert_eq!(rx.len(), 0);
+    assert!(rx.is_empty());
+
+    for i in 0..10 {
+        assert_ok!(tx.send(i).await);
+        assert_eq!(rx.len(), i + 1);
+    }
+
+    assert!(!rx.is_empty());
+
+    for i in (0..10).rev() {
+        assert!(rx.recv().await.is_some());
+        assert_eq!(rx.len(), i);
+    }
+
+    assert!(rx.is_empty());
+
+    // Dropping the senders pushes a close marker, which must not be counted.
+    drop(tx);
+    assert_eq!(rx.len(), 0);
+    assert!(rx.is_empty());
+    assert!(rx.recv().await.is_none());
+    assert_eq!(rx.len(), 0);
+}
+
+#[maybe_tokio_test]
+async fn len_and_is_empty_unbounded() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+
+    assert_eq!(rx.len(), 0);
+    assert!(rx.is_empty());
+
+    assert_ok!(tx.send(1));
+    assert_ok!(tx.send(2));
+    assert_eq!(rx.len(), 2);
+
+    assert_eq!(rx.recv().await, Some(1));
+    assert_eq!(rx.len(), 1);
+
+    drop(tx);
+    assert_eq!(rx.len(), 1);
+    assert_eq!(rx.recv().await, Some(2));
+    assert!(rx.is_empty());
+    assert!(rx.recv().await.is_none());
+}
+
+#[maybe_tokio_test]
+async fn close_drains_in_flight_in_order() {
+    let (tx, mut rx) = mpsc::channel::<i32>(10);
+
+    // Messages accepted before the shutdown...
+    assert_ok!(tx.send(1).await);
+    assert_ok!(tx.send(2).await);
+    assert_ok!(tx.send(3).await);
+
+    rx.close();
+
+    // ...are still delivered, in order, while new sends are refused.
+    assert_err!(tx.send(4).await);
+
+    assert_eq!(rx.recv().await, Some(1));
+    assert_eq!(rx.recv().await, Some(2));
+    assert_eq!(rx.recv().await, Some(3));
+    assert!(rx.recv().await.is_none());
+}
+
+#[maybe_tokio_test]
+async fn send_error_returns_value_when_receiver_already_gone() {
+    let (tx, rx) = mpsc::channel::<String>(4);
+    drop(rx);
+
+    // The payload comes back inside the error rather than being lost.
+    let mpsc::error::SendError(value) = tx.send("precious".to_string()).await.unwrap_err();
+    assert_eq!(value, "precious");
+}
+
+#[tokio::test]
+#[cfg(feature = "full")]
+async fn send_error_returns_value_when_receiver_drops_mid_send() {
+    let (tx, rx) = mpsc::channel::<String>(1);
+    assert_ok!(tx.send("buffered".to_string()).await);
+
+    // The channel is full, so this send parks waiting for capacity.
+    let mut parked = tokio_test::task::spawn(tx.send("parked".to_string()));
+    assert_pending!(parked.poll());
+
+    drop(rx);
+
+    assert!(parked.is_woken());
+    let mpsc::error::SendError(value) = assert_ready!(parked.poll()).unwrap_err();
+    assert_eq!(value, "parked");
+}
+
+#[tokio::test]
+#[cfg(feature = "full")]
+async fn close_wakes_blocked_sender_with_error() {
+    let (tx, mut rx) = mpsc::channel::<i32>(1);
+
+    // Fill the channel so the next send parks waiting for capacity.
+    assert_ok!(tx.send(1).await);
+
+    let mut blocked = tokio_test::task::spawn(tx.send(2));
+    assert_pending!(blocked.poll());
+
+    rx.close();
+
+    // The parked sender is woken and told the channel is closed; its
+    // message is handed back.
+    assert!(blocked.is_woken());
+    match assert_ready!(blocked.poll()) {
+        Err(mpsc::error::SendError(2)) => {}
+        res => panic!("unexpected send result: {:?}", res),
+    }
+
+    // The buffered message still drains before the terminal `None`.
+    assert_eq!(rx.recv().await, Some(1));
+    assert!(rx.recv().await.is_none());
+}
+
 #[maybe_tokio_test]
 async fn recv_close_gets_none_idle() {
     let (tx, mut rx) = mpsc::channel::<i32>(10);
@@ -480,6 +629,29 @@ async fn tx_close_gets_none() {
     assert!(rx.recv().await.is_none());
 }
 
+#[maybe_tokio_test]
+async fn try_send_returns_value_in_error() {
+    let (tx, mut rx) = mpsc::channel(1);
+
+    tx.try_send("hello").unwrap();
+
+    // A full channel hands the value back so it can be requeued or shed.
+    match assert_err!(tx.try_send("full")) {
+        TrySendError::Full(v) => assert_eq!(v, "full"),
+        _ => panic!(),
+    }
+
+    assert_eq!(rx.recv().await, Some("hello"));
+    drop(rx);
+
+    // A closed channel is distinguished from a full one, and also returns
+    // the value.
+    match assert_err!(tx.try_send("closed")) {
+        TrySendError::Closed(v) => assert_eq!(v, "closed"),
+        _ => panic!(),
+    }
+}
+
 #[maybe_tokio_test]
 async fn try_send_fail() {
     let (tx, mut rx) = mpsc::channel(1);
@@ -564,6 +736,42 @@ async fn drop_permit_releases_permit() {
     assert_ready_ok!(reserve2.poll());
 }
 
+#[tokio::test]
+#[cfg(feature = "full")]
+async fn reserve_owned_crosses_tasks() {
+    let (tx, mut rx) = mpsc::channel::<i32>(1);
+
+    // The owned permit keeps the sender alive by value and can be moved
+    // into another task; committing the message there cannot fail.
+    let permit = assert_ok!(tx.reserve_owned().await);
+
+    let handle = tokio::spawn(async move {
+        let tx = permit.send(42);
+        // `send` hands the sender back for reuse.
+        drop(tx);
+    });
+
+    assert_eq!(rx.recv().await, Some(42));
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+#[cfg(feature = "full")]
+async fn drop_unused_owned_permit_releases_capacity() {
+    let (tx, _rx) = mpsc::channel::<i32>(1);
+
+    let permit = assert_ok!(tx.clone().reserve_owned().await);
+
+    let mut reserve2 = tokio_test::task::spawn(tx.reserve());
+    assert_pending!(reserve2.poll());
+
+    // An abandoned owned permit must give its slot back to the channel.
+    drop(permit);
+
+    assert!(reserve2.is_woken());
+    assert_ready_ok!(reserve2.poll());
+}
+
 #[maybe_tokio_test]
 async fn dropping_rx_closes_channel() {
     let (tx, rx) = mpsc::channel(100);
@@ -10,6 +10,25 @@ use std::sync::atomic::Ordering::{Acquire, Release};
 use tokio::sync::mpsc::{self, channel, unbounded_channel};
 use tokio::sync::oneshot;
 
+#[tokio::test]
+async fn channel_closes_while_weak_sender_alive() {
+    let (tx, mut rx) = channel::<i32>(4);
+
+    // A registry-style weak handle outlives the real producers.
+    let registry = tx.downgrade();
+
+    tx.send(1).await.unwrap();
+    drop(tx);
+
+    // Buffered messages drain, then the channel reports closed even
+    // though the weak sender is still held.
+    assert_eq!(rx.recv().await, Some(1));
+    assert_eq!(rx.recv().await, None);
+
+    // Every strong sender is gone, so the weak handle cannot upgrade.
+    assert!(registry.upgrade().is_none());
+}
+
 #[tokio::test]
 async fn weak_sender() {
     let (tx, mut rx) = channel(11);
@@ -161,6 +161,35 @@ fn try_lock() {
     assert!(g3.is_ok());
 }
 
+#[test]
+fn get_mut_and_into_inner() {
+    let mut m: Mutex<usize> = Mutex::new(0);
+
+    // `&mut self` proves exclusivity: no locking required.
+    *m.get_mut() += 1;
+    assert_eq!(*m.get_mut(), 1);
+
+    // The value can be recovered without going through a guard.
+    assert_eq!(m.into_inner(), 1);
+}
+
+#[test]
+fn try_lock_does_not_jump_the_queue() {
+    let m: Mutex<usize> = Mutex::new(0);
+    let g = m.try_lock().unwrap();
+
+    let mut waiter = spawn(m.lock());
+    assert_pending!(waiter.poll());
+
+    // Releasing the lock hands it to the queued waiter, so an opportunistic
+    // `try_lock` cannot sneak in ahead of it.
+    drop(g);
+    assert!(m.try_lock().is_err());
+
+    assert!(waiter.is_woken());
+    let _g = assert_ready!(waiter.poll());
+}
+
 #[maybe_tokio_test]
 async fn debug_format() {
     let s = "debug";
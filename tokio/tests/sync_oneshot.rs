@@ -105,6 +105,34 @@ async fn async_rx_closed() {
     tx.closed().await;
 }
 
+#[tokio::test]
+#[cfg(feature = "full")]
+async fn closed_resolves_immediately_for_dropped_rx() {
+    let (mut tx, rx) = oneshot::channel::<()>();
+
+    drop(rx);
+
+    // The receiver is already gone: `closed()` must not hang, and
+    // `is_closed()` reports it without polling.
+    assert!(tx.is_closed());
+    tx.closed().await;
+}
+
+#[tokio::test]
+#[cfg(feature = "full")]
+async fn closed_lets_producer_abandon_work() {
+    let (mut tx, rx) = oneshot::channel::<i32>();
+
+    drop(rx);
+
+    // A producer can use `closed()` to skip expensive work once nobody is
+    // listening anymore.
+    tokio::select! {
+        _ = tx.closed() => {}
+        _ = std::future::pending::<()>() => unreachable!(),
+    }
+}
+
 #[test]
 fn explicit_close_poll() {
     // First, with message sent
@@ -191,6 +219,36 @@ fn close_try_recv_poll() {
     let _ = rx.poll();
 }
 
+#[test]
+fn try_recv_distinguishes_empty_from_closed() {
+    let (tx, mut rx) = oneshot::channel::<i32>();
+
+    // Nothing sent yet: the probe reports "not yet" without consuming
+    // the receiver, and can be repeated.
+    assert_eq!(Err(TryRecvError::Empty), rx.try_recv());
+    assert_eq!(Err(TryRecvError::Empty), rx.try_recv());
+
+    // The sender going away without sending is a distinct, terminal state.
+    drop(tx);
+    assert_eq!(Err(TryRecvError::Closed), rx.try_recv());
+}
+
+#[test]
+fn poll_recv_composes_with_other_futures() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut rx = task::spawn(rx);
+
+    // The receiver can be polled any number of times before completion,
+    // as happens when it is selected against a timer in a manual poll.
+    assert_pending!(rx.poll());
+    assert_pending!(rx.poll());
+
+    tx.send(99).unwrap();
+
+    assert!(rx.is_woken());
+    assert_eq!(Ok(99), assert_ready!(rx.poll()));
+}
+
 #[test]
 fn close_after_recv() {
     let (tx, mut rx) = oneshot::channel::<i32>();
@@ -289,3 +347,28 @@ fn sender_changes_task() {
 
     assert_ready!(task2.enter(|cx, _| tx.poll_closed(cx)));
 }
+
+#[test]
+fn send_returns_value_when_receiver_gone() {
+    let (tx, rx) = oneshot::channel();
+    drop(rx);
+
+    // The failed send hands the value back so the sender can clean it up.
+    let value = tx.send("unclaimed").unwrap_err();
+    assert_eq!(value, "unclaimed");
+}
+
+#[test]
+fn recv_reports_deliberate_sender_drop() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut rx = task::spawn(rx);
+
+    assert_pending!(rx.poll());
+
+    // Dropping the sender without sending resolves the receiver with an
+    // error, distinct from a value that simply has not arrived yet.
+    drop(tx);
+
+    assert!(rx.is_woken());
+    assert_ready_err!(rx.poll());
+}
@@ -148,3 +148,105 @@ fn no_panic_at_maxpermits() {
     let s = Semaphore::new(Semaphore::MAX_PERMITS - 1);
     s.add_permits(1);
 }
+
+#[test]
+fn acquire_many_is_fifo_fair() {
+    use tokio_test::task::spawn;
+    use tokio_test::{assert_pending, assert_ready};
+
+    let sem = Semaphore::new(0);
+
+    let mut big = spawn(sem.acquire_many(5));
+    assert_pending!(big.poll());
+
+    let mut small = spawn(sem.acquire_many(1));
+    assert_pending!(small.poll());
+
+    // Fewer permits than the head-of-line request: the later, smaller request
+    // must not jump the queue even though it could be satisfied.
+    sem.add_permits(3);
+    assert!(!small.is_woken());
+    assert_pending!(small.poll());
+    assert_pending!(big.poll());
+
+    // Once the large request is satisfied, the queue drains in order.
+    sem.add_permits(2);
+    let p = assert_ready!(big.poll()).unwrap();
+    assert_pending!(small.poll());
+
+    // Releasing the five permits lets the small request through.
+    drop(p);
+    let _p = assert_ready!(small.poll()).unwrap();
+    assert_eq!(sem.available_permits(), 4);
+}
+
+#[test]
+fn forget_permits_shrinks_pool() {
+    let sem = Semaphore::new(10);
+
+    assert_eq!(sem.forget_permits(3), 3);
+    assert_eq!(sem.available_permits(), 7);
+
+    // Only currently-available permits can be forgotten.
+    assert_eq!(sem.forget_permits(100), 7);
+    assert_eq!(sem.available_permits(), 0);
+    assert_eq!(sem.forget_permits(1), 0);
+}
+
+#[test]
+fn add_permits_wakes_queued_in_order() {
+    use tokio_test::task::spawn;
+    use tokio_test::{assert_pending, assert_ready};
+
+    let sem = Semaphore::new(0);
+
+    let mut w1 = spawn(sem.acquire());
+    assert_pending!(w1.poll());
+    let mut w2 = spawn(sem.acquire());
+    assert_pending!(w2.poll());
+    let mut w3 = spawn(sem.acquire());
+    assert_pending!(w3.poll());
+
+    // Growing a saturated semaphore wakes exactly enough waiters, in FIFO
+    // order, without over-waking.
+    sem.add_permits(2);
+
+    assert!(w1.is_woken());
+    assert!(w2.is_woken());
+    assert!(!w3.is_woken());
+
+    let _p1 = assert_ready!(w1.poll()).unwrap();
+    let _p2 = assert_ready!(w2.poll()).unwrap();
+    assert_pending!(w3.poll());
+
+    sem.add_permits(1);
+    assert!(w3.is_woken());
+    let _p3 = assert_ready!(w3.poll()).unwrap();
+}
+
+#[test]
+fn close_cancels_waiters_but_keeps_outstanding_permits() {
+    use tokio_test::task::spawn;
+    use tokio_test::{assert_pending, assert_ready};
+
+    let sem = Semaphore::new(1);
+    assert!(!sem.is_closed());
+
+    let held = sem.try_acquire().unwrap();
+
+    let mut waiter = spawn(sem.acquire());
+    assert_pending!(waiter.poll());
+
+    sem.close();
+    assert!(sem.is_closed());
+
+    // Every queued acquirer is woken with an error, and new attempts fail.
+    assert!(waiter.is_woken());
+    assert!(assert_ready!(waiter.poll()).is_err());
+    assert!(sem.try_acquire().is_err());
+
+    // A permit handed out before the close stays valid; returning it after
+    // the close is a quiet no-op rather than reopening the semaphore.
+    drop(held);
+    assert!(sem.try_acquire().is_err());
+}
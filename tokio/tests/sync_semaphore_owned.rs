@@ -48,6 +48,37 @@ async fn acquire() {
     j.await.unwrap();
 }
 
+#[tokio::test]
+#[cfg(feature = "full")]
+async fn bounds_concurrent_spawned_tasks() {
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+    const LIMIT: usize = 3;
+
+    let sem = Arc::new(Semaphore::new(LIMIT));
+    let running = Arc::new(AtomicUsize::new(0));
+    let mut handles = Vec::new();
+
+    for _ in 0..20 {
+        // Acquire before spawning; the owned permit moves into the task
+        // and is released when the task finishes.
+        let permit = sem.clone().acquire_owned().await.unwrap();
+        let running = running.clone();
+        handles.push(tokio::spawn(async move {
+            let now = running.fetch_add(1, SeqCst) + 1;
+            assert!(now <= LIMIT);
+            tokio::task::yield_now().await;
+            running.fetch_sub(1, SeqCst);
+            drop(permit);
+        }));
+    }
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    assert_eq!(sem.available_permits(), LIMIT);
+}
+
 #[tokio::test]
 #[cfg(feature = "full")]
 async fn acquire_many() {
@@ -255,6 +255,93 @@ fn borrow_and_update() {
     assert!(rx.has_changed().is_err());
 }
 
+#[test]
+fn send_if_neq_only_notifies_on_change() {
+    let (tx, mut rx) = watch::channel(0);
+
+    // Equal value: no version bump, no wakeup.
+    let mut changed = spawn(rx.changed());
+    assert_pending!(changed.poll());
+    assert!(!tx.send_if_neq(0));
+    assert!(!changed.is_woken());
+    drop(changed);
+
+    // Different value: receivers are notified as usual.
+    let mut changed = spawn(rx.changed());
+    assert_pending!(changed.poll());
+    assert!(tx.send_if_neq(1));
+    assert!(changed.is_woken());
+    assert_ready!(changed.poll()).unwrap();
+    drop(changed);
+
+    assert_eq!(*rx.borrow_and_update(), 1);
+}
+
+#[test]
+fn plain_borrow_does_not_mark_seen() {
+    let (tx, mut rx) = watch::channel("one");
+
+    tx.send("two").unwrap();
+
+    // `borrow` leaves the changed flag untouched...
+    assert_eq!(*rx.borrow(), "two");
+    assert!(rx.has_changed().unwrap());
+
+    // ...while `borrow_and_update` clears it atomically with the read.
+    assert_eq!(*rx.borrow_and_update(), "two");
+    assert!(!rx.has_changed().unwrap());
+
+    // An update landing after the snapshot is still observed.
+    tx.send("three").unwrap();
+    assert!(rx.has_changed().unwrap());
+}
+
+#[test]
+fn subscribe_starts_with_current_value_seen() {
+    let (tx, _rx) = watch::channel("initial");
+
+    tx.send("config-v2").unwrap();
+
+    // A late subscriber starts already caught up: it can read the current
+    // value but `changed` does not fire for it.
+    let mut rx = tx.subscribe();
+    assert_eq!(*rx.borrow(), "config-v2");
+
+    {
+        let mut t = spawn(rx.changed());
+        assert_pending!(t.poll());
+
+        // Only a genuinely newer value wakes it.
+        tx.send("config-v3").unwrap();
+        assert!(t.is_woken());
+        assert_ready_ok!(t.poll());
+    }
+    assert_eq!(*rx.borrow_and_update(), "config-v3");
+}
+
+#[test]
+fn receiver_count_tracks_clones_and_drops() {
+    let (tx, rx) = watch::channel("one");
+    assert_eq!(tx.receiver_count(), 1);
+
+    let rx2 = rx.clone();
+    let rx3 = tx.subscribe();
+    assert_eq!(tx.receiver_count(), 3);
+
+    drop(rx2);
+    drop(rx3);
+    assert_eq!(tx.receiver_count(), 1);
+
+    // `closed` resolves once the count reaches zero.
+    let mut t = spawn(tx.closed());
+    assert_pending!(t.poll());
+
+    drop(rx);
+    assert_eq!(tx.receiver_count(), 0);
+    assert!(t.is_woken());
+    assert_ready!(t.poll());
+}
+
 #[test]
 fn reopened_after_subscribe() {
     let (tx, rx) = watch::channel("one");
@@ -299,3 +386,68 @@ fn send_modify_panic() {
     assert_ready_ok!(task.poll());
     assert_eq!(*rx.borrow_and_update(), "three");
 }
+
+#[test]
+fn wait_for_checks_initial_value_immediately() {
+    let (_tx, mut rx) = watch::channel(true);
+
+    // The predicate is satisfied by the value already in the channel, so
+    // the future resolves without any send.
+    let mut task = spawn(rx.wait_for(|ready| *ready));
+    let val = assert_ready_ok!(task.poll());
+    assert!(*val);
+}
+
+#[test]
+fn wait_for_resolves_when_predicate_matches() {
+    let (tx, mut rx) = watch::channel(0);
+
+    let mut task = spawn(rx.wait_for(|v| *v >= 3));
+    assert_pending!(task.poll());
+
+    // Intermediate values that fail the predicate keep the future pending.
+    tx.send(1).unwrap();
+    assert!(task.is_woken());
+    assert_pending!(task.poll());
+
+    tx.send(3).unwrap();
+    assert!(task.is_woken());
+    let val = assert_ready_ok!(task.poll());
+    assert_eq!(*val, 3);
+}
+
+#[test]
+fn wait_for_errs_when_sender_drops_mid_wait() {
+    let (tx, mut rx) = watch::channel(0);
+
+    let mut task = spawn(rx.wait_for(|v| *v >= 3));
+    assert_pending!(task.poll());
+
+    drop(tx);
+    assert!(task.is_woken());
+    assert_ready_err!(task.poll());
+}
+
+#[test]
+fn final_value_then_drop_models_terminal_state() {
+    let (tx, mut rx) = watch::channel("running");
+
+    // Publish the terminal state and hang up. Receivers observe the final
+    // value first, and only then see the channel report closure, so a
+    // definite end state is never lost to the disconnect.
+    tx.send("finished").unwrap();
+    drop(tx);
+
+    let mut task = spawn(rx.changed());
+    assert_ready_ok!(task.poll());
+    drop(task);
+    assert_eq!(*rx.borrow_and_update(), "finished");
+
+    // With the final value consumed, further waits report the close.
+    let mut task = spawn(rx.changed());
+    assert_ready_err!(task.poll());
+    drop(task);
+
+    // The last value stays readable even after the error.
+    assert_eq!(*rx.borrow(), "finished");
+}
@@ -218,3 +218,44 @@ fn test_abort_task_that_panics_on_drop_returned() {
         assert!(handle.await.unwrap_err().is_panic());
     });
 }
+
+/// Checks that aborting a task that already completed is a no-op: the
+/// handle still yields the task's output.
+#[test]
+fn test_abort_after_completion_is_noop() {
+    let rt = Builder::new_current_thread().build().unwrap();
+
+    rt.block_on(async move {
+        let handle = tokio::spawn(async move { 42 });
+
+        // Let the task run to completion.
+        tokio::task::yield_now().await;
+
+        handle.abort();
+        handle.abort();
+        assert_eq!(handle.await.unwrap(), 42);
+    });
+}
+
+/// Checks that aborting before the task is first polled prevents the
+/// future from ever running.
+#[test]
+fn test_abort_before_first_poll() {
+    let rt = Builder::new_current_thread().build().unwrap();
+
+    rt.block_on(async move {
+        let ran = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let ran2 = ran.clone();
+
+        let handle = tokio::spawn(async move {
+            ran2.store(true, std::sync::atomic::Ordering::SeqCst);
+        });
+
+        // The spawned task has not yet been polled; abort it first.
+        handle.abort();
+
+        let err = handle.await.unwrap_err();
+        assert!(err.is_cancelled());
+        assert!(!ran.load(std::sync::atomic::Ordering::SeqCst));
+    });
+}
@@ -11,6 +11,24 @@ mod support {
     pub(crate) mod mpsc_stream;
 }
 
+#[tokio::test]
+#[cfg(panic = "unwind")]
+async fn blocking_task_panic_is_reported_as_error() {
+    // A panicking closure must not take down the runtime; the panic is
+    // delivered to the caller through the join error.
+    let err = task::spawn_blocking(|| -> &'static str {
+        panic!("getaddrinfo exploded");
+    })
+    .await
+    .unwrap_err();
+
+    assert!(err.is_panic());
+
+    // The pool is still usable for the next blocking call.
+    let out = assert_ok!(task::spawn_blocking(|| "still alive").await);
+    assert_eq!(out, "still alive");
+}
+
 #[tokio::test]
 async fn basic_blocking() {
     // Run a few times
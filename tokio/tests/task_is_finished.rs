@@ -0,0 +1,47 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi")))] // Wasi doesn't support panic recovery
+
+use tokio::sync::oneshot;
+use tokio::task;
+
+#[tokio::test]
+async fn is_finished_tracks_completion() {
+    let (tx, rx) = oneshot::channel::<()>();
+
+    let handle = tokio::spawn(async move {
+        rx.await.unwrap();
+    });
+
+    // Still blocked on the channel: not finished, and the probe does not
+    // consume the handle.
+    task::yield_now().await;
+    assert!(!handle.is_finished());
+    assert!(!handle.is_finished());
+
+    tx.send(()).unwrap();
+
+    // Once the handle resolves, the flag must agree.
+    while !handle.is_finished() {
+        task::yield_now().await;
+    }
+    handle.await.unwrap();
+}
+
+#[cfg(panic = "unwind")]
+#[tokio::test]
+async fn is_finished_after_panic_and_abort() {
+    let panicked = tokio::spawn(async {
+        panic!("nope");
+    });
+    let aborted = tokio::spawn(std::future::pending::<()>());
+
+    aborted.abort();
+
+    // Finished covers every terminal state: success, panic, cancellation.
+    while !panicked.is_finished() || !aborted.is_finished() {
+        task::yield_now().await;
+    }
+
+    assert!(panicked.await.unwrap_err().is_panic());
+    assert!(aborted.await.unwrap_err().is_cancelled());
+}
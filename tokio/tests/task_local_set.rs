@@ -234,6 +234,38 @@ async fn all_spawns_are_local() {
         .await;
 }
 
+#[cfg(not(target_os = "wasi"))] // Wasi doesn't support threads
+#[tokio::test(flavor = "multi_thread")]
+async fn rc_state_is_usable_across_awaits() {
+    use std::rc::Rc;
+
+    // The headline use case for `LocalSet`: `Rc`-based state held across
+    // await points, even when the outer runtime is multi-threaded. Every
+    // task stays on the one thread driving the set, so the shared count
+    // needs no synchronization.
+    let counter = Rc::new(Cell::new(0));
+
+    LocalSet::new()
+        .run_until(async {
+            let handles = (0..8)
+                .map(|_| {
+                    let counter = counter.clone();
+                    task::spawn_local(async move {
+                        counter.set(counter.get() + 1);
+                        task::yield_now().await;
+                        counter.set(counter.get() + 1);
+                    })
+                })
+                .collect::<Vec<_>>();
+            for joined in handles {
+                joined.await.unwrap();
+            }
+        })
+        .await;
+
+    assert_eq!(counter.get(), 16);
+}
+
 #[cfg(not(target_os = "wasi"))] // Wasi doesn't support threads
 #[tokio::test(flavor = "multi_thread")]
 async fn nested_spawn_is_local() {
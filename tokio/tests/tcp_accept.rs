@@ -94,6 +94,52 @@ async fn no_extra_poll() {
     assert_eq!(npolls.load(SeqCst), 1 + 2);
 }
 
+#[tokio::test]
+async fn graceful_shutdown_of_accept_loop() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = assert_ok!(TcpListener::bind("127.0.0.1:0").await);
+    let addr = listener.local_addr().unwrap();
+
+    let (shutdown_tx, mut shutdown_rx) = oneshot::channel::<()>();
+
+    // The accept loop races each accept against the shutdown signal and
+    // exits cleanly once signalled, after in-flight connections are handed
+    // off to their own tasks.
+    let server = tokio::spawn(async move {
+        let mut served = 0;
+        loop {
+            tokio::select! {
+                res = listener.accept() => {
+                    let (mut socket, _) = res.unwrap();
+                    served += 1;
+                    tokio::spawn(async move {
+                        let mut buf = [0; 16];
+                        let n = socket.read(&mut buf).await.unwrap();
+                        socket.write_all(&buf[..n]).await.unwrap();
+                    });
+                }
+                _ = &mut shutdown_rx => break,
+            }
+        }
+        served
+    });
+
+    // A connection accepted before the signal is still served to
+    // completion.
+    let mut cli = assert_ok!(TcpStream::connect(&addr).await);
+    cli.write_all(b"hello").await.unwrap();
+    let mut buf = [0; 16];
+    let n = cli.read(&mut buf).await.unwrap();
+    assert_eq!(&buf[..n], b"hello");
+
+    shutdown_tx.send(()).unwrap();
+    assert_eq!(server.await.unwrap(), 1);
+
+    // The listener is gone: new connections are no longer accepted.
+    assert!(TcpStream::connect(&addr).await.is_err());
+}
+
 #[tokio::test]
 async fn accept_many() {
     use futures::future::poll_fn;
@@ -155,3 +201,79 @@ async fn accept_many() {
         notified_rx.recv().await.unwrap();
     }
 }
+
+#[test]
+fn accepted_socket_moves_to_another_runtime() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::runtime::Builder;
+
+    // Worker-per-core designs accept on one runtime and run the
+    // connection on another. The stream is detached from the accepting
+    // driver via `into_std` and re-registered on the target runtime with
+    // `from_std`.
+    let acceptor = Builder::new_current_thread().enable_all().build().unwrap();
+    let worker = Builder::new_current_thread().enable_all().build().unwrap();
+
+    let (sock_tx, sock_rx) = std::sync::mpsc::channel();
+    let (addr_tx, addr_rx) = std::sync::mpsc::channel();
+
+    let accept_thread = std::thread::spawn(move || {
+        acceptor.block_on(async move {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            addr_tx.send(listener.local_addr().unwrap()).unwrap();
+
+            let (socket, _) = listener.accept().await.unwrap();
+            let std_socket = socket.into_std().unwrap();
+            sock_tx.send(std_socket).unwrap();
+        });
+    });
+
+    let worker_thread = std::thread::spawn(move || {
+        worker.block_on(async move {
+            let std_socket = sock_rx.recv().unwrap();
+            let mut socket = TcpStream::from_std(std_socket).unwrap();
+
+            let mut buf = [0; 16];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(&buf[..n]).await.unwrap();
+        });
+    });
+
+    let addr = addr_rx.recv().unwrap();
+    let mut cli = std::net::TcpStream::connect(addr).unwrap();
+    std::io::Write::write_all(&mut cli, b"ping").unwrap();
+    let mut buf = [0; 4];
+    std::io::Read::read_exact(&mut cli, &mut buf).unwrap();
+    assert_eq!(&buf, b"ping");
+
+    accept_thread.join().unwrap();
+    worker_thread.join().unwrap();
+}
+
+#[tokio::test]
+async fn accept_is_cancel_safe() {
+    use tokio::io::AsyncWriteExt;
+
+    let listener = assert_ok!(TcpListener::bind("127.0.0.1:0").await);
+    let addr = listener.local_addr().unwrap();
+
+    let (tick_tx, mut tick_rx) = mpsc::unbounded_channel::<()>();
+
+    // Repeatedly abandon an in-flight `accept` in favour of another branch;
+    // no connection may be lost to the cancelled futures.
+    for _ in 0..5 {
+        tick_tx.send(()).unwrap();
+        tokio::select! {
+            biased;
+            _ = tick_rx.recv() => {}
+            res = listener.accept() => {
+                panic!("unexpected accept: {:?}", res.map(|(_, addr)| addr));
+            }
+        }
+    }
+
+    let mut cli = assert_ok!(TcpStream::connect(&addr).await);
+    let (_, peer_addr) = assert_ok!(listener.accept().await);
+    assert_eq!(peer_addr, cli.local_addr().unwrap());
+    assert_ok!(cli.write_all(b"ok").await);
+}
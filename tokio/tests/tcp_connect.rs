@@ -227,3 +227,26 @@ mod linux {
     }
 }
 */
+
+#[tokio::test(start_paused = true)]
+#[cfg(target_os = "linux")]
+async fn connect_timeout_aborts_pending_connect() {
+    use std::time::Duration;
+    use tokio::net::TcpSocket;
+    use tokio::time::timeout;
+
+    // A listener with a tiny backlog that is never accepted from: once the
+    // backlog is full, further handshakes stall and the timeout must win.
+    let socket = TcpSocket::new_v4().unwrap();
+    socket.bind("127.0.0.1:0".parse().unwrap()).unwrap();
+    let listener = socket.listen(1).unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // Connect until the deadline fires first; the dropped connect future
+    // closes the in-flight socket.
+    let mut backlog = Vec::new();
+    while let Ok(stream) = timeout(Duration::from_secs(5), TcpStream::connect(addr)).await {
+        backlog.push(assert_ok!(stream));
+        assert!(backlog.len() <= 64, "backlog never filled");
+    }
+}
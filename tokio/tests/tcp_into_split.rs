@@ -53,6 +53,46 @@ async fn split() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn owned_halves_move_into_separate_tasks() -> Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    let (stream, (mut peer, _)) = try_join! {
+        TcpStream::connect(&addr),
+        listener.accept(),
+    }?;
+    let (mut read_half, mut write_half) = stream.into_split();
+
+    // Each half is 'static and can be spawned on its own task.
+    let writer = tokio::spawn(async move {
+        write_half.write_all(b"request").await.unwrap();
+        // Shutting down the write half half-closes only that direction.
+        write_half.shutdown().await.unwrap();
+    });
+
+    let reader = tokio::spawn(async move {
+        let mut buf = vec![0u8; 32];
+        let len = read_half.read(&mut buf).await.unwrap();
+        buf.truncate(len);
+        buf
+    });
+
+    // The peer observes our EOF, then replies over the still-open
+    // direction.
+    let mut buf = vec![0u8; 32];
+    let len = peer.read(&mut buf).await?;
+    assert_eq!(&buf[..len], b"request");
+    assert_eq!(peer.read(&mut buf).await?, 0);
+
+    peer.write_all(b"response").await?;
+
+    writer.await.unwrap();
+    assert_eq!(reader.await.unwrap(), b"response");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn reunite() -> Result<()> {
     let listener = net::TcpListener::bind("127.0.0.1:0")?;
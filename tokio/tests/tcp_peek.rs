@@ -8,6 +8,7 @@ use tokio_test::assert_ok;
 
 use std::thread;
 use std::{io::Write, net};
+use tokio::io::AsyncWriteExt;
 
 #[tokio::test]
 async fn peek() {
@@ -27,3 +28,37 @@ async fn peek() {
     let n = assert_ok!(left.read(&mut buf).await);
     assert_eq!([1, 2, 3, 4], buf[..n]);
 }
+
+#[tokio::test]
+async fn poll_peek_composes_in_poll() {
+    use std::future::poll_fn;
+    use tokio::io::ReadBuf;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let client = TcpStream::connect(addr).await.unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+
+    // No data yet: poll_peek registers read interest and returns `Pending`.
+    let mut raw = [0u8; 16];
+    {
+        let mut fut = tokio_test::task::spawn(poll_fn(|cx| {
+            let mut buf = ReadBuf::new(&mut raw);
+            client.poll_peek(cx, &mut buf)
+        }));
+        tokio_test::assert_pending!(fut.poll());
+
+        server.write_all(b"sniff").await.unwrap();
+
+        // The readiness wakeup arrives and the peek completes.
+        assert_eq!(assert_ok!(fut.await), 5);
+    }
+    assert_eq!(&raw[..5], b"sniff");
+
+    // Peeking does not consume: the data is still there for a real read.
+    let mut client = client;
+    let mut buf = [0u8; 16];
+    let n = assert_ok!(client.read(&mut buf).await);
+    assert_eq!(&buf[..n], b"sniff");
+}
@@ -26,3 +26,56 @@ async fn shutdown() {
     let n = assert_ok!(io::copy(&mut rd, &mut wr).await);
     assert_eq!(n, 0);
 }
+
+#[tokio::test]
+async fn write_shutdown_keeps_read_open() {
+    let srv = assert_ok!(TcpListener::bind("127.0.0.1:0").await);
+    let addr = assert_ok!(srv.local_addr());
+
+    let peer = tokio::spawn(async move {
+        let (mut stream, _) = assert_ok!(srv.accept().await);
+
+        // Read until EOF, then send a reply over the still-open direction.
+        let mut request = Vec::new();
+        assert_ok!(stream.read_to_end(&mut request).await);
+        assert_eq!(request, b"request");
+
+        assert_ok!(stream.write_all(b"reply").await);
+    });
+
+    let mut stream = assert_ok!(TcpStream::connect(&addr).await);
+    assert_ok!(stream.write_all(b"request").await);
+
+    // Shut down the write half: the peer observes EOF, but our read half
+    // stays usable.
+    assert_ok!(AsyncWriteExt::shutdown(&mut stream).await);
+
+    let mut reply = Vec::new();
+    assert_ok!(stream.read_to_end(&mut reply).await);
+    assert_eq!(reply, b"reply");
+
+    assert_ok!(peer.await);
+}
+
+#[tokio::test]
+async fn shutdown_flushes_buffered_writes() {
+    let srv = assert_ok!(TcpListener::bind("127.0.0.1:0").await);
+    let addr = assert_ok!(srv.local_addr());
+
+    let peer = tokio::spawn(async move {
+        let (mut stream, _) = assert_ok!(srv.accept().await);
+        let mut data = Vec::new();
+        assert_ok!(stream.read_to_end(&mut data).await);
+        data
+    });
+
+    let stream = assert_ok!(TcpStream::connect(&addr).await);
+
+    // Buffer a write without flushing; `shutdown` must flush it before
+    // half-closing, so the peer sees the full payload followed by EOF.
+    let mut writer = io::BufWriter::new(stream);
+    assert_ok!(writer.write_all(b"buffered bytes").await);
+    assert_ok!(writer.shutdown().await);
+
+    assert_eq!(peer.await.unwrap(), b"buffered bytes");
+}
@@ -72,3 +72,51 @@ async fn basic_linger() {
     srv.set_linger(Some(Duration::new(0, 0))).unwrap();
     assert_eq!(srv.linger().unwrap(), Some(Duration::new(0, 0)));
 }
+
+#[tokio::test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+async fn reuseport_multiple_accept_loops() {
+    let addr = assert_ok!("127.0.0.1:0".parse());
+
+    // Two listeners share one port via SO_REUSEPORT, e.g. to run an accept
+    // loop per worker.
+    let srv1 = assert_ok!(TcpSocket::new_v4());
+    assert_ok!(srv1.set_reuseport(true));
+    assert_ok!(srv1.bind(addr));
+    let srv1 = assert_ok!(srv1.listen(128));
+    let addr = srv1.local_addr().unwrap();
+
+    let srv2 = assert_ok!(TcpSocket::new_v4());
+    assert_ok!(srv2.set_reuseport(true));
+    assert_ok!(srv2.bind(addr));
+    let srv2 = assert_ok!(srv2.listen(128));
+
+    // Every connection to the shared port is handed to exactly one listener.
+    for _ in 0..10 {
+        let sock = assert_ok!(TcpSocket::new_v4());
+        let _cli = assert_ok!(sock.connect(addr).await);
+
+        tokio::select! {
+            res = srv1.accept() => { assert_ok!(res); }
+            res = srv2.accept() => { assert_ok!(res); }
+        }
+    }
+}
+
+#[tokio::test]
+async fn options_apply_before_connect() {
+    let addr = assert_ok!("127.0.0.1:0".parse());
+    let srv = assert_ok!(TcpSocket::new_v4());
+    assert_ok!(srv.bind(addr));
+    let srv = assert_ok!(srv.listen(128));
+    let addr = srv.local_addr().unwrap();
+
+    // Socket options are set on the unconnected socket, so they are in
+    // effect from the very first write after the connect resolves.
+    let sock = assert_ok!(TcpSocket::new_v4());
+    assert_ok!(sock.set_nodelay(true));
+    assert!(sock.nodelay().unwrap());
+
+    let stream = assert_ok!(sock.connect(addr).await);
+    assert!(stream.nodelay().unwrap());
+}
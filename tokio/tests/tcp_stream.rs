@@ -254,6 +254,61 @@ async fn create_pair() -> (TcpStream, TcpStream) {
     (client, server)
 }
 
+#[tokio::test]
+async fn ready_decodes_both_directions() {
+    let (client, mut server) = create_pair().await;
+
+    server.write_all(b"ping").await.unwrap();
+
+    // Wait until the peer's bytes have arrived so read readiness is
+    // pending alongside the (always present) write readiness.
+    client.ready(Interest::READABLE).await.unwrap();
+
+    // One notification then carries both flags.
+    let ready = client
+        .ready(Interest::READABLE | Interest::WRITABLE)
+        .await
+        .unwrap();
+
+    assert!(ready.is_readable());
+    assert!(ready.is_writable());
+
+    // Asking for a single direction reports only that direction's bit as
+    // the guaranteed-ready set the caller asked about.
+    let ready = client.ready(Interest::WRITABLE).await.unwrap();
+    assert!(ready.is_writable());
+}
+
+#[tokio::test]
+async fn half_close_tracks_directions_independently() {
+    let (client, mut server) = create_pair().await;
+
+    // Peer closes its write side: our read direction reports EOF while
+    // the write direction stays usable.
+    server.shutdown().await.unwrap();
+
+    // The FIN may not have been observed yet; wait for the read side to
+    // report it before checking the combined readiness.
+    let ready = loop {
+        let ready = client
+            .ready(Interest::READABLE | Interest::WRITABLE)
+            .await
+            .unwrap();
+        if ready.is_read_closed() {
+            break ready;
+        }
+        tokio::task::yield_now().await;
+    };
+
+    assert!(ready.is_writable());
+
+    // The write half really is still open: the peer receives our bytes.
+    client.try_write(b"reply").unwrap();
+    let mut buf = [0; 16];
+    let n = server.read(&mut buf).await.unwrap();
+    assert_eq!(&buf[..n], b"reply");
+}
+
 fn read_until_pending(stream: &mut TcpStream) -> usize {
     let mut buf = vec![0u8; 1024 * 1024];
     let mut total = 0;
@@ -398,3 +453,53 @@ async fn write_closed() {
 
     assert!(!ready_event.is_write_closed());
 }
+
+#[tokio::test]
+#[cfg(target_os = "linux")]
+async fn tcp_info() {
+    let (mut client, mut server) = create_pair().await;
+
+    assert_ok!(client.write_all(b"ping").await);
+    let mut buf = [0u8; 4];
+    assert_ok!(server.read_exact(&mut buf).await);
+
+    let info = assert_ok!(client.tcp_info());
+    assert!(info.snd_cwnd > 0);
+}
+
+#[tokio::test]
+async fn vectored_write_gathers_header_and_body() {
+    let (client, server) = create_pair().await;
+
+    // A protocol frame assembled from separate header and body buffers,
+    // written without copying them into one allocation first.
+    let header = [0u8, 0, 5];
+    let body = b"hello";
+    let bufs = [io::IoSlice::new(&header), io::IoSlice::new(body)];
+
+    let mut sent = 0;
+    while sent < header.len() + body.len() {
+        client.writable().await.unwrap();
+        match client.try_write_vectored(&bufs[..]) {
+            Ok(n) => sent += n,
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+            Err(e) => panic!("error = {:?}", e),
+        }
+        // A small frame on an empty socket goes out in one gather call.
+        break;
+    }
+    assert_eq!(sent, header.len() + body.len());
+
+    let mut read = [0u8; 8];
+    let mut i = 0;
+    while i < read.len() {
+        server.readable().await.unwrap();
+        match server.try_read(&mut read[i..]) {
+            Ok(n) => i += n,
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+            Err(e) => panic!("error = {:?}", e),
+        }
+    }
+    assert_eq!(&read[..3], &header);
+    assert_eq!(&read[3..], body);
+}
@@ -39,6 +39,28 @@ async fn interval_zero_duration() {
 //                  Ready(s + p)                           Ready(s + 4p)       |           |
 //                                                                 Ready(s + 5p)           |
 //                                                                             Ready(s + 6p)
+// A consumer that does some work between ticks (but stays within the
+// period) must observe ticks on the fixed schedule `start + n * period`,
+// with no drift accumulating from when it happens to poll.
+#[tokio::test(start_paused = true)]
+async fn ticks_do_not_drift() {
+    let start = Instant::now();
+    time::advance(ms(1)).await;
+
+    let mut i = task::spawn(time::interval_at(start, ms(300)));
+
+    check_interval_poll!(i, start, 0);
+
+    for n in 1..=5 {
+        // "work" for part of the period before waiting for the tick
+        time::advance(ms(120)).await;
+        check_interval_poll!(i, start);
+
+        time::advance(ms(180)).await;
+        check_interval_poll!(i, start, n * 300);
+    }
+}
+
 #[tokio::test(start_paused = true)]
 async fn burst() {
     let start = Instant::now();
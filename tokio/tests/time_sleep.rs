@@ -39,6 +39,34 @@ async fn is_elapsed() {
     assert!(sleep.is_elapsed());
 }
 
+#[tokio::test]
+async fn idle_driver_sleeps_to_next_deadline() {
+    time::pause();
+
+    // With auto-advance, an otherwise idle driver jumps straight to the
+    // earliest pending deadline instead of spinning in smaller steps.
+    let now = Instant::now();
+    let dur = Duration::from_secs(300);
+
+    time::sleep(dur).await;
+
+    assert_elapsed!(now, dur);
+}
+
+#[tokio::test]
+async fn timeout_builds_on_sleep() {
+    time::pause();
+
+    // `timeout` is the composition the timer exists for: a pending
+    // operation races the deadline and loses.
+    let res = time::timeout(ms(50), std::future::pending::<()>()).await;
+    assert!(res.is_err());
+
+    // ... and wins when it completes first.
+    let res = time::timeout(ms(50), time::sleep(ms(10))).await;
+    assert!(res.is_ok());
+}
+
 #[tokio::test]
 async fn delayed_sleep_level_0() {
     time::pause();
@@ -133,6 +133,23 @@ async fn deadline_future_elapses() {
     assert_ready_err!(fut.poll());
 }
 
+#[tokio::test(start_paused = true)]
+async fn losing_future_survives_the_timeout() {
+    let (tx, rx) = oneshot::channel::<i32>();
+
+    // Wrapping a `&mut` future means the timeout only drops the borrow when
+    // the timer wins, so the loser can keep being driven afterwards — the
+    // pattern for `wait_timeout`-style helpers that must not kill the
+    // underlying operation.
+    let mut operation = Box::pin(rx);
+
+    let res = timeout(ms(100), &mut operation).await;
+    assert!(res.is_err());
+
+    tx.send(9).unwrap();
+    assert_eq!(operation.await.unwrap(), 9);
+}
+
 fn ms(n: u64) -> Duration {
     Duration::from_millis(n)
 }
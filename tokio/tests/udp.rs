@@ -45,6 +45,55 @@ async fn send_recv_poll() -> std::io::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn connected_recv_filters_other_peers() -> std::io::Result<()> {
+    let receiver = UdpSocket::bind("127.0.0.1:0").await?;
+    let peer = UdpSocket::bind("127.0.0.1:0").await?;
+    let stranger = UdpSocket::bind("127.0.0.1:0").await?;
+
+    receiver.connect(peer.local_addr()?).await?;
+
+    // A datagram from an unconnected peer must not be delivered to the
+    // connected socket; only the connected peer's datagram arrives.
+    stranger
+        .send_to(b"not for you", receiver.local_addr()?)
+        .await?;
+    peer.send_to(MSG, receiver.local_addr()?).await?;
+
+    let mut recv_buf = [0u8; 32];
+    let len = receiver.recv(&mut recv_buf[..]).await?;
+
+    assert_eq!(&recv_buf[..len], MSG);
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg(not(target_os = "macos"))]
+async fn multicast_group_management() -> std::io::Result<()> {
+    use std::net::Ipv4Addr;
+
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    let group = Ipv4Addr::new(239, 0, 0, 77);
+    let interface = Ipv4Addr::LOCALHOST;
+
+    socket.set_multicast_loop_v4(true)?;
+    assert!(socket.multicast_loop_v4()?);
+    socket.set_multicast_ttl_v4(1)?;
+    assert_eq!(socket.multicast_ttl_v4()?, 1);
+
+    // Each join is tracked per (group, interface) and is independently
+    // leaveable; leaving a group that is no longer joined errors.
+    socket.join_multicast_v4(group, interface)?;
+    socket.leave_multicast_v4(group, interface)?;
+    assert!(socket.leave_multicast_v4(group, interface).is_err());
+
+    // The membership can be re-established after a leave.
+    socket.join_multicast_v4(group, interface)?;
+    socket.leave_multicast_v4(group, interface)?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn send_to_recv_from() -> std::io::Result<()> {
     let sender = UdpSocket::bind("127.0.0.1:0").await?;
@@ -409,3 +409,33 @@ async fn epollhup() -> io::Result<()> {
     assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
     Ok(())
 }
+
+#[tokio::test]
+async fn socket_addrs() -> std::io::Result<()> {
+    let dir = tempfile::Builder::new()
+        .prefix("tokio-uds-tests")
+        .tempdir()
+        .unwrap();
+    let sock_path = dir.path().join("addrs.sock");
+
+    let listener = UnixListener::bind(&sock_path)?;
+    assert_eq!(
+        listener.local_addr()?.as_pathname(),
+        Some(sock_path.as_path())
+    );
+
+    let accept = listener.accept();
+    let connect = UnixStream::connect(&sock_path);
+    let ((server, peer_addr), client) = try_join(accept, connect).await?;
+
+    // The server end mirrors the TCP API: the bound path is visible on both
+    // the accepted pair and the client's peer address.
+    assert_eq!(server.local_addr()?.as_pathname(), Some(sock_path.as_path()));
+    assert_eq!(client.peer_addr()?.as_pathname(), Some(sock_path.as_path()));
+
+    // The client side is unnamed.
+    assert!(peer_addr.as_pathname().is_none());
+    assert!(client.local_addr()?.as_pathname().is_none());
+
+    Ok(())
+}